@@ -3,12 +3,72 @@ use anchor_lang::prelude::*;
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 pub const PUBKEY_SIZE: usize = 32;
 pub const U8_SIZE: usize = 1;
+pub const BOOL_SIZE: usize = 1;
+pub const U16_SIZE: usize = 2;
+pub const U64_SIZE: usize = 8;
+pub const I64_SIZE: usize = 8;
+pub const VEC_PREFIX_SIZE: usize = 4;
+pub const OPTION_PREFIX_SIZE: usize = 1;
+
+pub const MAX_GUARDIANS: usize = 5;
+#[cfg(feature = "local")]
+pub const RECOVERY_CANCELLATION_WINDOW_SECONDS: i64 = 5;
+#[cfg(not(feature = "local"))]
+pub const RECOVERY_CANCELLATION_WINDOW_SECONDS: i64 = 60 * 60 * 24 * 3;
+#[cfg(feature = "local")]
+pub const MIN_INACTIVITY_PERIOD_SECONDS: i64 = 5;
+#[cfg(not(feature = "local"))]
+pub const MIN_INACTIVITY_PERIOD_SECONDS: i64 = 60 * 60 * 24 * 30;
+pub const MAX_CO_OWNERS: usize = 4;
+pub const MAX_VAULTS_PER_OWNER: usize = 10;
+pub const VAULT_VERSION: u8 = 1;
+pub const VAULT_RESERVED_SIZE: usize = 31;
+// Vaults created before versioning only stored the owner and bump
+pub const LEGACY_VAULT_SIZE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U8_SIZE;
+
+pub const MAX_BLOCKED_MCCS: usize = 20;
+pub const MAX_ALLOWED_PAYEES: usize = 10;
+pub const MAX_CATEGORY_CAPS: usize = 10;
+pub const CATEGORY_CAP_PERIOD_SECONDS: i64 = 60 * 60 * 24 * 30;
+pub const MERCHANT_ID_SIZE: usize = 32;
 
 pub const DRIFT_MARKET_INDEX_USDC: u16 = 0;
 pub const DRIFT_MARKET_INDEX_SOL: u16 = 1;
+pub const DRIFT_PERP_MARKET_INDEX_SOL: u16 = 0;
+pub const DRIFT_MARKET_INDEX_LST: u16 = 6;
+pub const DRIFT_MARKET_INDEX_PYUSD: u16 = 22;
+
+// Reserved so the stop-loss can be found and cancelled without tracking its Drift order id
+pub const STOP_LOSS_USER_ORDER_ID: u8 = 255;
+
+pub const DRIFT_SPOT_PRECISION_DECREASE_EXPONENT: u32 = 19;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+// Drift's MARGIN_PRECISION, a custom margin ratio above this would require more than full collateral
+pub const MAX_CUSTOM_MARGIN_RATIO: u32 = 10_000;
+// Hedge orders are limit orders this far through the oracle price, so a thin book can't fill them at any price
+pub const HEDGE_MAX_SLIPPAGE_BPS: u64 = 100;
+
+// USD values use the same 6 decimals as USDC
+pub const PRICE_PRECISION: u64 = 1_000_000;
+pub const PRICE_PRECISION_EXPONENT: i32 = 6;
+pub const MAX_ORACLE_STALENESS_SECONDS: i64 = 60;
+pub const MAX_ORACLE_CONFIDENCE_BPS: u64 = 200;
+
+// Authority of the Drift account that refers new vaults when the client doesn't pass another referrer
+#[constant]
+pub const QUARTZ_REFERRER: Pubkey = pubkey!("5XY5pQbBjwv8ByBxKPNE7Xyb9dVcdFgd51xcxKDJjGWE");
 
 pub const DRIFT_PROGRAM_ID: Pubkey = pubkey!("dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH");
 
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+pub const STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+// JitoSOL, the stake pool itself is checked by the stake pool program against this mint. The test validator
+// clones the mint and its Drift market, and loads tests/mock-stake-pool at STAKE_POOL_PROGRAM_ID so the
+// stand-in can sign as the mint authority
+#[constant]
+pub const LST_MINT_ADDRESS: Pubkey = pubkey!("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn");
 
 #[cfg(feature = "local")]
 #[constant]
@@ -23,10 +83,10 @@ pub const USDC_MINT_ADDRESS: Pubkey = pubkey!("8zGuJQqwhZafTah7Uc7Z4tXRnguqkn5KL
 #[constant]
 pub const USDC_MINT_ADDRESS: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
 
-#[cfg(feature = "local")]
+// The native mint has the same address on every cluster, including localnet
 #[constant]
-pub const WSOL_MINT_ADDRESS: Pubkey = pubkey!("");
+pub const WSOL_MINT_ADDRESS: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 
-#[cfg(not(feature = "local"))]
+// Token-2022 mint
 #[constant]
-pub const WSOL_MINT_ADDRESS: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+pub const PYUSD_MINT_ADDRESS: Pubkey = pubkey!("2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo");
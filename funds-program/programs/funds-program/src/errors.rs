@@ -13,5 +13,185 @@ pub enum ErrorCode {
     #[msg("Invalid Drift program address")]
     InvalidDriftProgram,
     #[msg("Unable to load account loader")]
-    UnableToLoadAccountLoader
+    UnableToLoadAccountLoader,
+    #[msg("Too many guardians")]
+    TooManyGuardians,
+    #[msg("Guardian threshold must be between 1 and the number of guardians")]
+    InvalidGuardianThreshold,
+    #[msg("Invalid guardian")]
+    InvalidGuardian,
+    #[msg("Signer is not a guardian of this vault")]
+    NotAGuardian,
+    #[msg("Guardian has already approved this recovery")]
+    RecoveryAlreadyApproved,
+    #[msg("Not enough guardian approvals to execute recovery")]
+    RecoveryThresholdNotMet,
+    #[msg("Recovery cancellation window has not passed")]
+    RecoveryWindowActive,
+    #[msg("Invalid new owner")]
+    InvalidNewOwner,
+    #[msg("Inactivity period is too short")]
+    InactivityPeriodTooShort,
+    #[msg("Signer is not the beneficiary of this vault")]
+    InvalidBeneficiary,
+    #[msg("Vault owner is still active")]
+    OwnerStillActive,
+    #[msg("Invalid allowance period")]
+    InvalidAllowancePeriod,
+    #[msg("Allowance has expired")]
+    AllowanceExpired,
+    #[msg("Amount exceeds remaining allowance")]
+    AllowanceExceeded,
+    #[msg("Invalid payment schedule")]
+    InvalidPaymentSchedule,
+    #[msg("Scheduled payment is not due yet")]
+    PaymentNotDue,
+    #[msg("Payment schedule has ended")]
+    PaymentScheduleEnded,
+    #[msg("Invalid payment expiry")]
+    InvalidPaymentExpiry,
+    #[msg("Claimable payment has expired")]
+    PaymentExpired,
+    #[msg("Claimable payment has not expired yet")]
+    PaymentNotExpired,
+    #[msg("Invalid Drift delegate")]
+    InvalidDriftDelegate,
+    #[msg("Invalid Drift market index")]
+    InvalidMarketIndex,
+    #[msg("Invalid DCA plan")]
+    InvalidDcaPlan,
+    #[msg("DCA order is not due yet")]
+    DcaOrderNotDue,
+    #[msg("DCA plan has ended")]
+    DcaPlanEnded,
+    #[msg("Invalid hedge ratio")]
+    InvalidHedgeRatio,
+    #[msg("Signer is not allowed to manage the hedge")]
+    InvalidHedgeAuthority,
+    #[msg("Invalid Drift spot market account")]
+    InvalidSpotMarket,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Invalid stop-loss")]
+    InvalidStopLoss,
+    #[msg("Vault already has an open stop-loss")]
+    StopLossAlreadySet,
+    #[msg("Vault has no stop-loss")]
+    NoStopLoss,
+    #[msg("User order id is reserved")]
+    ReservedUserOrderId,
+    #[msg("Vault stakes SOL deposits, use the LST deposit instead")]
+    LstModeEnabled,
+    #[msg("Vault does not have LST mode enabled")]
+    LstModeDisabled,
+    #[msg("Invalid stake pool program")]
+    InvalidStakePoolProgram,
+    #[msg("Batch must contain at least one leg")]
+    EmptyBatch,
+    #[msg("Remaining accounts do not match the batch legs")]
+    InvalidBatchAccounts,
+    #[msg("Invalid vault token account")]
+    InvalidVaultTokenAccount,
+    #[msg("Price update is missing the Pyth receiver, guardian set or price feed account")]
+    MissingPriceUpdateAccounts,
+    #[msg("Invalid oracle account")]
+    InvalidOracle,
+    #[msg("Oracle source is not supported")]
+    UnsupportedOracleSource,
+    #[msg("Oracle price is stale")]
+    StaleOracle,
+    #[msg("Oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+    #[msg("Too many spend rules")]
+    TooManySpendRules,
+    #[msg("Merchant category is blocked")]
+    MerchantCategoryBlocked,
+    #[msg("Merchant category monthly cap exceeded")]
+    CategoryCapExceeded,
+    #[msg("Payee is not on the vault's allow-list")]
+    PayeeNotAllowed,
+    #[msg("Invalid card limit")]
+    InvalidCardLimit,
+    #[msg("Card is not active")]
+    CardNotActive,
+    #[msg("Card is not frozen")]
+    CardNotFrozen,
+    #[msg("Card has been cancelled")]
+    CardCancelled,
+    #[msg("Card has expired")]
+    CardExpired,
+    #[msg("Card limit exceeded")]
+    CardLimitExceeded,
+    #[msg("Team mode is not enabled for this vault")]
+    TeamModeDisabled,
+    #[msg("Invalid team member")]
+    InvalidTeamMember,
+    #[msg("Invalid team member limit")]
+    InvalidTeamMemberLimit,
+    #[msg("Team member spend limit exceeded")]
+    TeamMemberLimitExceeded,
+    #[msg("Spend is above the team approval threshold")]
+    TeamSpendNeedsApproval,
+    #[msg("Signer is not a team admin")]
+    NotATeamAdmin,
+    #[msg("Spend has already been approved")]
+    TeamSpendAlreadyApproved,
+    #[msg("Spend has not been approved")]
+    TeamSpendNotApproved,
+    #[msg("Spend does not match its pending approval")]
+    InvalidPendingApproval,
+    #[msg("Vault is not a child of this vault")]
+    NotAChildVault,
+    #[msg("Invalid child vault owner")]
+    InvalidChildOwner,
+    #[msg("Child vaults are managed by their parent vault")]
+    ManagedByParentVault,
+    #[msg("Child vault is frozen")]
+    ChildVaultFrozen,
+    #[msg("Child vault is not frozen")]
+    ChildVaultNotFrozen,
+    #[msg("Invalid child vault top-up schedule")]
+    InvalidChildTopUp,
+    #[msg("Child vault top-up is not due yet")]
+    ChildTopUpNotDue,
+    #[msg("Child vault top-up exceeds the parent's deposit")]
+    ChildTopUpExceedsDeposit,
+    #[msg("Signer is not an owner of this vault")]
+    NotAVaultOwner,
+    #[msg("Too many co-owners")]
+    TooManyCoOwners,
+    #[msg("Invalid co-owner")]
+    InvalidCoOwner,
+    #[msg("Co-owner threshold must be between 1 and the number of owners")]
+    InvalidCoOwnerThreshold,
+    #[msg("Not enough co-owners signed")]
+    CoOwnerThresholdNotMet,
+    #[msg("Invalid vault ID")]
+    InvalidVaultId,
+    #[msg("Too many vaults for this owner")]
+    TooManyVaults,
+    #[msg("Vault is already on the latest version")]
+    VaultAlreadyMigrated,
+    #[msg("Guardians can't be changed while a recovery is pending")]
+    RecoveryPending,
+    #[msg("Vault is paused")]
+    VaultPaused,
+    #[msg("Vault spend limit exceeded")]
+    SpendLimitExceeded,
+    #[msg("Spend limit period must be positive")]
+    InvalidSpendLimit,
+    #[msg("Custom margin ratio is out of bounds")]
+    InvalidMarginRatio,
+    #[msg("Referrer user and user stats must be passed together")]
+    InvalidReferrer,
+    #[msg("Oracle price update is not fully verified")]
+    OracleNotFullyVerified,
+    #[msg("Allowance can't be used for this kind of payment")]
+    InvalidAllowanceKind,
+    #[msg("LST mode needs the stake pool and LST market accounts")]
+    MissingStakePoolAccounts,
+    #[msg("Pending approval was made before the member was last added")]
+    StalePendingApproval,
+    #[msg("Initiator does not match the pending recovery")]
+    InvalidRecoveryInitiator
 }
@@ -2,4 +2,31 @@ mod config;
 pub use config::*;
 
 mod balance;
-pub use balance::*;
\ No newline at end of file
+pub use balance::*;
+
+mod recovery;
+pub use recovery::*;
+
+mod inheritance;
+pub use inheritance::*;
+
+mod payments;
+pub use payments::*;
+
+mod insurance_fund;
+pub use insurance_fund::*;
+
+mod orders;
+pub use orders::*;
+
+mod hedge;
+pub use hedge::*;
+
+mod cards;
+pub use cards::*;
+
+mod team;
+pub use team::*;
+
+mod child_vaults;
+pub use child_vaults::*;
@@ -8,4 +8,27 @@ mod withdraw_usdc;
 pub use withdraw_usdc::*;
 
 mod deposit_usdc;
-pub use deposit_usdc::*;
\ No newline at end of file
+pub use deposit_usdc::*;
+mod withdraw_lamports_lst;
+pub use withdraw_lamports_lst::*;
+
+mod batch_deposit;
+pub use batch_deposit::*;
+
+mod batch_withdraw;
+pub use batch_withdraw::*;
+
+mod deposit_wsol;
+pub use deposit_wsol::*;
+
+mod withdraw_wsol;
+pub use withdraw_wsol::*;
+
+mod deposit_spl;
+pub use deposit_spl::*;
+
+mod withdraw_spl;
+pub use withdraw_spl::*;
+
+mod get_collateral_value;
+pub use get_collateral_value::*;
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_2022::{spl_token_2022, Token2022},
+    token_interface::{
+        self, 
+        harvest_withheld_tokens_to_mint, 
+        HarvestWithheldTokensToMint, 
+        Mint
+    }
+};
+use drift_cpi::{
+    cpi::withdraw, 
+    Withdraw
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::{DRIFT_PROGRAM_ID, WSOL_MINT_ADDRESS}, 
+    errors::ErrorCode, 
+    instructions::BalanceLeg,
+    state::Vault,
+    utils::{
+        get_batch_leg_accounts, get_batch_market_accounts, get_drift_market_index, get_token_account_amount, 
+        init_vault_token_account, mint_has_transfer_fee
+    }
+};
+
+#[derive(Accounts)]
+pub struct BatchWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub drift_signer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Only needed when a leg's mint is a Token-2022 mint
+    pub token_2022_program: Option<Program<'info, Token2022>>
+}
+
+// Remaining accounts hold one group per leg, see get_batch_leg_accounts.
+// Drift runs its margin check after every withdraw CPI, not once per batch, so a batch that passes in total can 
+// still fail on an early leg and revert as a whole. Order legs to keep the vault healthy throughout, and send 
+// batch_deposit first when rebalancing.
+pub fn batch_withdraw_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchWithdraw<'info>>, 
+    legs: Vec<BalanceLeg>
+) -> Result<()> {
+    require!(!legs.is_empty(), ErrorCode::EmptyBatch);
+
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let leg_accounts = get_batch_leg_accounts(legs.len(), ctx.remaining_accounts)?;
+    let market_accounts = get_batch_market_accounts(
+        vec![
+            ctx.accounts.const_account.to_account_info(),
+            ctx.accounts.additional_account.to_account_info()
+        ],
+        vec![
+            ctx.accounts.spot_market_sol.to_account_info(),
+            ctx.accounts.spot_market_usdc.to_account_info()
+        ],
+        &leg_accounts
+    );
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    for (leg, accounts) in legs.into_iter().zip(leg_accounts.iter()) {
+        require!(get_drift_market_index(accounts.mint.key)? == leg.market_index, ErrorCode::InvalidMarketIndex);
+
+        ctx.accounts.vault.record_owner_withdraw(
+            &ctx.accounts.owner.key(),
+            leg.market_index,
+            leg.amount,
+            &market_accounts,
+            current_ts
+        )?;
+
+        let is_token_2022 = *accounts.mint.owner == spl_token_2022::ID;
+        let token_program = if is_token_2022 {
+            ctx.accounts.token_2022_program.as_ref()
+                .ok_or(ErrorCode::InvalidBatchAccounts)?
+                .to_account_info()
+        } else {
+            ctx.accounts.token_program.to_account_info()
+        };
+        let decimals = {
+            let mint_data = accounts.mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &mint_data[..])?.decimals
+        };
+
+        init_vault_token_account(
+            &accounts.vault_spl,
+            &ctx.accounts.vault.to_account_info(),
+            &accounts.mint,
+            &ctx.accounts.owner.to_account_info(),
+            &token_program,
+            &ctx.accounts.system_program.to_account_info()
+        )?;
+
+        // Build Drift Withdraw CPI, Drift expects the mint last for Token-2022 markets
+        let mut remaining_accounts = market_accounts.clone();
+        if is_token_2022 {
+            remaining_accounts.push(accounts.mint.clone());
+        }
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.drift_program.to_account_info(),
+            Withdraw {
+                state: ctx.accounts.drift_state.to_account_info(),
+                user: ctx.accounts.drift_user.to_account_info(),
+                user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                spot_market_vault: accounts.spot_market_vault.clone(),
+                drift_signer: ctx.accounts.drift_signer.to_account_info(),
+                user_token_account: accounts.vault_spl.clone(),
+                token_program: token_program.clone(),
+            },
+            signer_seeds
+        ).with_remaining_accounts(remaining_accounts);
+
+        withdraw(cpi_ctx, leg.market_index, leg.amount, leg.reduce_only)?;
+
+        // Transfer tokens to the owner, wSOL is unwrapped when the account is closed.
+        // Transfer fees can reduce what arrives from Drift, so forward the received balance
+
+        if *accounts.mint.key != WSOL_MINT_ADDRESS {
+            let owner_spl = accounts.owner_spl.as_ref().ok_or(ErrorCode::InvalidBatchAccounts)?;
+            let received_amount = get_token_account_amount(&accounts.vault_spl)?;
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.clone(), 
+                    token_interface::TransferChecked { 
+                        from: accounts.vault_spl.clone(), 
+                        mint: accounts.mint.clone(),
+                        to: owner_spl.clone(), 
+                        authority: ctx.accounts.vault.to_account_info()
+                    }, 
+                    signer_seeds
+                ),
+                received_amount,
+                decimals
+            )?;
+        }
+
+        // Harvest withheld fees so the vault token account can be closed
+
+        if mint_has_transfer_fee(&accounts.mint)? {
+            harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    token_program.clone(),
+                    HarvestWithheldTokensToMint {
+                        token_program_id: token_program.clone(),
+                        mint: accounts.mint.clone()
+                    }
+                ),
+                vec![accounts.vault_spl.clone()]
+            )?;
+        }
+
+        // Close vault token account
+
+        let cpi_ctx_close = CpiContext::new_with_signer(
+            token_program,
+            token_interface::CloseAccount {
+                account: accounts.vault_spl.clone(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signer_seeds
+        );
+        token_interface::close_account(cpi_ctx_close)?;
+    }
+
+    Ok(())
+}
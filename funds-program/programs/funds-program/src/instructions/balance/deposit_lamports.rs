@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::{
     token,
-    token::{Mint, Token, SyncNative}, 
+    token::{accessor, Mint, Token, SyncNative}, 
     token::TokenAccount
 };
 use drift_cpi::{
@@ -15,30 +15,29 @@ use drift_accounts::{
     UserStats as DriftUserStats
 };
 use crate::{
-    constants::{DRIFT_PROGRAM_ID, WSOL_MINT_ADDRESS, DRIFT_MARKET_INDEX_SOL},
+    constants::{
+        DRIFT_MARKET_INDEX_LST, DRIFT_MARKET_INDEX_SOL, DRIFT_PROGRAM_ID, LST_MINT_ADDRESS, STAKE_POOL_PROGRAM_ID, 
+        WSOL_MINT_ADDRESS
+    },
     errors::ErrorCode,
-    state::Vault
+    stake_pool::{deposit_sol, DepositSol},
+    state::Vault,
+    utils::init_vault_token_account
 };
 
 #[derive(Accounts)]
 pub struct DepositLamports<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
     )]
     pub vault: Box<Account<'info, Vault>>,
 
-    #[account(
-        init,
-        seeds = [vault.key().as_ref(), wsol_mint.key().as_ref()],
-        bump,
-        payer = owner,
-        token::mint = wsol_mint,
-        token::authority = vault
-    )]
-    pub vault_wsol: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Created in the handler as the [vault, wSOL mint] token account, which checks the address. Unused in LST mode
+    #[account(mut)]
+    pub vault_wsol: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -89,30 +88,96 @@ pub struct DepositLamports<'info> {
     )]
     pub drift_program: UncheckedAccount<'info>,
 
-    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks. The LST oracle in LST mode
     pub const_account: UncheckedAccount<'info>,
 
-    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks. The LST spot market in LST mode
     #[account(mut)]
     pub spot_market: UncheckedAccount<'info>,
 
-    pub system_program: Program<'info, System>
+    pub system_program: Program<'info, System>,
+
+    // The remaining accounts are only needed in LST mode, to stake the SOL and deposit the LST
+
+    /// CHECK: Created in the handler as the [vault, LST mint] token account, which checks the address
+    #[account(mut)]
+    pub vault_lst: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        constraint = lst_mint.key() == LST_MINT_ADDRESS @ ErrorCode::InvalidMintAddress
+    )]
+    pub lst_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", (DRIFT_MARKET_INDEX_LST).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump,
+    )]
+    pub lst_spot_market_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which checks it against the pool mint
+    #[account(mut)]
+    pub stake_pool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which performs the security checks
+    pub stake_pool_withdraw_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which performs the security checks
+    #[account(mut)]
+    pub reserve_stake: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which performs the security checks
+    #[account(mut)]
+    pub manager_fee_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = stake_pool_program.key() == STAKE_POOL_PROGRAM_ID @ ErrorCode::InvalidStakePoolProgram
+    )]
+    pub stake_pool_program: Option<UncheckedAccount<'info>>
 }
 
 pub fn deposit_lamports_handler(
     ctx: Context<DepositLamports>, 
     amount: u64,
     reduce_only: bool
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    if ctx.accounts.vault.lst_mode {
+        deposit_lamports_as_lst(ctx, amount, reduce_only)
+    } else {
+        deposit_lamports_as_wsol(ctx, amount, reduce_only)
+    }
+}
+
+fn deposit_lamports_as_wsol(
+    ctx: Context<DepositLamports>, 
+    amount: u64,
+    reduce_only: bool
 ) -> Result<()> {
     let vault_bump = ctx.accounts.vault.bump;
-    let owner = ctx.accounts.owner.key();
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
     let seeds = &[
         b"vault",
-        owner.as_ref(),
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
         &[vault_bump]
     ];
     let signer_seeds = &[&seeds[..]];
 
+    init_vault_token_account(
+        &ctx.accounts.vault_wsol.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.wsol_mint.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info()
+    )?;
+
     // Transfer SOL from user to vault wSOL account
     let cpi_ctx_transfer = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
@@ -169,4 +234,111 @@ pub fn deposit_lamports_handler(
     token::close_account(cpi_ctx_close)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+fn deposit_lamports_as_lst(
+    ctx: Context<DepositLamports>, 
+    amount: u64,
+    reduce_only: bool
+) -> Result<()> {
+    let (
+        Some(vault_lst),
+        Some(lst_mint),
+        Some(lst_spot_market_vault),
+        Some(stake_pool),
+        Some(stake_pool_withdraw_authority),
+        Some(reserve_stake),
+        Some(manager_fee_account),
+        Some(stake_pool_program)
+    ) = (
+        &ctx.accounts.vault_lst,
+        &ctx.accounts.lst_mint,
+        &ctx.accounts.lst_spot_market_vault,
+        &ctx.accounts.stake_pool,
+        &ctx.accounts.stake_pool_withdraw_authority,
+        &ctx.accounts.reserve_stake,
+        &ctx.accounts.manager_fee_account,
+        &ctx.accounts.stake_pool_program
+    ) else {
+        return err!(ErrorCode::MissingStakePoolAccounts);
+    };
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    init_vault_token_account(
+        &vault_lst.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &lst_mint.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info()
+    )?;
+
+    // Stake SOL from the owner into the pool, minting the LST into the vault.
+    // The vault is its own referrer so any referral fee stays with the user.
+    deposit_sol(
+        DepositSol {
+            stake_pool_program: stake_pool_program.to_account_info(),
+            stake_pool: stake_pool.to_account_info(),
+            withdraw_authority: stake_pool_withdraw_authority.to_account_info(),
+            reserve_stake: reserve_stake.to_account_info(),
+            lamports_from: ctx.accounts.owner.to_account_info(),
+            pool_tokens_to: vault_lst.to_account_info(),
+            manager_fee_account: manager_fee_account.to_account_info(),
+            referrer_pool_tokens: vault_lst.to_account_info(),
+            pool_mint: lst_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info()
+        },
+        amount
+    )?;
+
+    let lst_amount = accessor::amount(&vault_lst.to_account_info())?;
+
+    // Build Drift Deposit CPI
+    let mut cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Deposit {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: lst_spot_market_vault.to_account_info(),
+            user_token_account: vault_lst.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    // Add remaining accounts and send CPI
+    cpi_ctx.remaining_accounts = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.spot_market.to_account_info(),
+    ];
+
+    deposit(cpi_ctx, DRIFT_MARKET_INDEX_LST, lst_amount, reduce_only)?;
+
+    // Close LST vault
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: vault_lst.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    token::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
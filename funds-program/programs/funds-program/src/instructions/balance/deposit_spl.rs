@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::ID as TOKEN_2022_PROGRAM_ID,
+    token_interface::{
+        self, 
+        harvest_withheld_tokens_to_mint, 
+        HarvestWithheldTokensToMint, 
+        Mint, 
+        TokenAccount, 
+        TokenInterface
+    }
+};
+use drift_cpi::{
+    cpi::deposit, 
+    Deposit
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_SOL, DRIFT_MARKET_INDEX_USDC, DRIFT_PROGRAM_ID}, 
+    errors::ErrorCode, 
+    state::Vault,
+    utils::{get_drift_market_index, mint_has_transfer_fee}
+};
+
+#[derive(Accounts)]
+pub struct DepositSpl<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+        payer = owner,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault_spl: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_spl: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", get_drift_market_index(&mint.key())?.to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        token::mint = mint,
+        token::token_program = token_program,
+        bump,
+    )]
+    pub spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_spl_handler(
+    ctx: Context<DepositSpl>, 
+    amount: u64,
+    reduce_only: bool
+) -> Result<()> {
+    // SOL and USDC keep their dedicated instructions
+    let market_index = get_drift_market_index(&ctx.accounts.mint.key())?;
+    require!(
+        market_index != DRIFT_MARKET_INDEX_SOL && market_index != DRIFT_MARKET_INDEX_USDC, 
+        ErrorCode::InvalidMarketIndex
+    );
+
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Transfer from owner's token account to vault_spl
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(), 
+            token_interface::TransferChecked { 
+                from: ctx.accounts.owner_spl.to_account_info(), 
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_spl.to_account_info(), 
+                authority: ctx.accounts.owner.to_account_info()
+            }
+        ),
+        amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    // Transfer fees and other extensions can reduce what arrives, so deposit the received balance
+    ctx.accounts.vault_spl.reload()?;
+    let received_amount = ctx.accounts.vault_spl.amount;
+
+    // Build Drift Deposit CPI
+    let mut cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Deposit {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            user_token_account: ctx.accounts.vault_spl.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    // Add remaining accounts, Drift expects the mint last for Token-2022 markets
+    cpi_ctx.remaining_accounts = vec![
+        ctx.accounts.oracle.to_account_info(),
+        ctx.accounts.spot_market.to_account_info(),
+    ];
+    if ctx.accounts.token_program.key() == TOKEN_2022_PROGRAM_ID {
+        cpi_ctx.remaining_accounts.push(ctx.accounts.mint.to_account_info());
+    }
+
+    deposit(cpi_ctx, market_index, received_amount, reduce_only)?;
+
+    // Harvest withheld fees so the vault token account can be closed
+
+    if mint_has_transfer_fee(&ctx.accounts.mint.to_account_info())? {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info()
+                }
+            ),
+            vec![ctx.accounts.vault_spl.to_account_info()]
+        )?;
+    }
+
+    // Close vault token account
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.vault_spl.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    token_interface::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
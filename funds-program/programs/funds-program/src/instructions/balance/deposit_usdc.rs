@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}
+    associated_token::AssociatedToken, 
+    token_interface::{self, Mint, TokenAccount, TokenInterface}
 };
 use drift_cpi::{
     cpi::deposit, 
@@ -21,9 +22,9 @@ use crate::{
 pub struct DepositUsdc<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -35,7 +36,7 @@ pub struct DepositUsdc<'info> {
         token::mint = usdc_mint,
         token::authority = vault
     )]
-    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+    pub vault_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -43,9 +44,10 @@ pub struct DepositUsdc<'info> {
     #[account(
         mut,
         associated_token::mint = usdc_mint,
-        associated_token::authority = owner
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
     )]
-    pub owner_usdc: Box<Account<'info, TokenAccount>>,
+    pub owner_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
@@ -78,14 +80,14 @@ pub struct DepositUsdc<'info> {
         token::mint = usdc_mint,
         bump,
     )]
-    pub spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         constraint = usdc_mint.key() == USDC_MINT_ADDRESS @ ErrorCode::InvalidMintAddress
     )]
-    pub usdc_mint: Box<Account<'info, Mint>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
 
@@ -116,27 +118,33 @@ pub fn deposit_usdc_handler(
     amount_micro_cents: u64,
     reduce_only: bool
 ) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
     let vault_bump = ctx.accounts.vault.bump;
-    let owner = ctx.accounts.owner.key();
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
     let seeds = &[
         b"vault",
-        owner.as_ref(),
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
         &[vault_bump]
     ];
     let signer_seeds = &[&seeds[..]];
 
     // Transfer USDC from owner's ATA to vault_usdc
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(), 
-            token::Transfer { 
+            token_interface::TransferChecked { 
                 from: ctx.accounts.owner_usdc.to_account_info(), 
+                mint: ctx.accounts.usdc_mint.to_account_info(),
                 to: ctx.accounts.vault_usdc.to_account_info(), 
                 authority: ctx.accounts.owner.to_account_info()
             }
         ),
-        amount_micro_cents
+        amount_micro_cents,
+        ctx.accounts.usdc_mint.decimals
     )?;
 
     // Build Drift Withdraw CPI
@@ -168,14 +176,14 @@ pub fn deposit_usdc_handler(
 
     let cpi_ctx_close = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        token::CloseAccount {
+        token_interface::CloseAccount {
             account: ctx.accounts.vault_usdc.to_account_info(),
             destination: ctx.accounts.owner_usdc.to_account_info(),
             authority: ctx.accounts.vault.to_account_info(),
         },
         signer_seeds
     );
-    token::close_account(cpi_ctx_close)?;
+    token_interface::close_account(cpi_ctx_close)?;
 
     Ok(())
 }
\ No newline at end of file
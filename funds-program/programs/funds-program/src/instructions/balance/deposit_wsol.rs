@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}
+};
+use drift_cpi::{
+    cpi::deposit, 
+    Deposit
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_SOL, DRIFT_PROGRAM_ID, WSOL_MINT_ADDRESS}, 
+    errors::ErrorCode, 
+    state::Vault
+};
+
+#[derive(Accounts)]
+pub struct DepositWsol<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.lst_mode @ ErrorCode::LstModeEnabled
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [vault.key().as_ref(), wsol_mint.key().as_ref()],
+        bump,
+        payer = owner,
+        token::mint = wsol_mint,
+        token::authority = vault
+    )]
+    pub vault_wsol: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_wsol: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", (DRIFT_MARKET_INDEX_SOL).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        token::mint = wsol_mint,
+        bump,
+    )]
+    pub spot_market_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        constraint = wsol_mint.key() == WSOL_MINT_ADDRESS @ ErrorCode::InvalidMintAddress
+    )]
+    pub wsol_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_wsol_handler(
+    ctx: Context<DepositWsol>, 
+    amount: u64,
+    reduce_only: bool
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Transfer wSOL from owner's ATA to vault_wsol
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(), 
+            token::Transfer { 
+                from: ctx.accounts.owner_wsol.to_account_info(), 
+                to: ctx.accounts.vault_wsol.to_account_info(), 
+                authority: ctx.accounts.owner.to_account_info()
+            }
+        ),
+        amount
+    )?;
+
+    // Build Drift Deposit CPI
+    let mut cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Deposit {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            user_token_account: ctx.accounts.vault_wsol.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    // Add remaining accounts and send CPI
+    cpi_ctx.remaining_accounts = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info(),
+    ];
+
+    deposit(cpi_ctx, DRIFT_MARKET_INDEX_SOL, amount, reduce_only)?;
+
+    // Close vault wSOL, the account is empty so only rent goes back to the owner
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.vault_wsol.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    token::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
\ No newline at end of file
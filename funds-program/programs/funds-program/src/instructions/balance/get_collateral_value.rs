@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use drift_accounts::{
+    SpotBalanceType,
+    User as DriftUser
+};
+use crate::{
+    constants::DRIFT_PROGRAM_ID, 
+    errors::ErrorCode, 
+    price::{get_spot_market_price, get_usd_value},
+    state::Vault,
+    utils::{find_spot_market, get_spot_deposit_amount}
+};
+
+#[derive(Accounts)]
+pub struct GetCollateralValue<'info> {
+    #[account(
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+// Returns the USD value of the vault's deposits across every Drift spot market, for clients to simulate.
+// The spot market and oracle of each deposit are passed as remaining accounts
+pub fn get_collateral_value_handler(
+    ctx: Context<GetCollateralValue>
+) -> Result<u64> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let drift_user = ctx.accounts.drift_user.load()
+        .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+
+    let mut deposits = drift_user.spot_positions.iter().filter(|position| 
+        position.scaled_balance > 0 && position.balance_type == SpotBalanceType::Deposit
+    );
+
+    deposits.try_fold(0u64, |total, position| {
+        let spot_market = find_spot_market(ctx.remaining_accounts, position.market_index)?;
+        let deposit_amount = get_spot_deposit_amount(&drift_user, &spot_market)?;
+        let price = get_spot_market_price(&spot_market, ctx.remaining_accounts, current_ts)?;
+        let value = get_usd_value(deposit_amount, spot_market.decimals, price)?;
+
+        total.checked_add(value).ok_or(error!(ErrorCode::MathOverflow))
+    })
+}
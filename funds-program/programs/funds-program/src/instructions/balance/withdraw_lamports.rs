@@ -16,16 +16,18 @@ use drift_accounts::{
 use crate::{
     constants::{DRIFT_MARKET_INDEX_SOL, DRIFT_PROGRAM_ID, WSOL_MINT_ADDRESS}, 
     errors::ErrorCode, 
-    state::Vault
+    state::Vault,
+    utils::{post_price_update, PythPriceUpdate}
 };
 
 #[derive(Accounts)]
 pub struct WithdrawLamports<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -104,23 +106,62 @@ pub struct WithdrawLamports<'info> {
     /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
     pub spot_market_usdc: UncheckedAccount<'info>,
 
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub pyth_solana_receiver: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub guardian_set: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    #[account(mut)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn withdraw_lamports_handler(
     ctx: Context<WithdrawLamports>, 
     amount: u64,
-    reduce_only: bool
+    reduce_only: bool,
+    price_update: Option<PythPriceUpdate>
 ) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
     let vault_bump = ctx.accounts.vault.bump;
-    let owner = ctx.accounts.owner.key();
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
     let seeds = &[
         b"vault",
-        owner.as_ref(),
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
         &[vault_bump]
     ];
     let signer_seeds = &[&seeds[..]];
 
+    post_price_update(
+        price_update,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.drift_program.to_account_info(),
+        ctx.accounts.pyth_solana_receiver.as_ref(),
+        ctx.accounts.guardian_set.as_ref(),
+        ctx.accounts.price_feed.as_ref()
+    )?;
+
+    let market_accounts = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info()
+    ];
+
+    ctx.accounts.vault.record_owner_withdraw(
+        &ctx.accounts.owner.key(),
+        DRIFT_MARKET_INDEX_SOL,
+        amount,
+        &market_accounts,
+        Clock::get()?.unix_timestamp
+    )?;
+
     // Build Drift Withdraw CPI
     let cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.drift_program.to_account_info(),
@@ -135,12 +176,7 @@ pub fn withdraw_lamports_handler(
             token_program: ctx.accounts.token_program.to_account_info(),
         },
         signer_seeds
-    ).with_remaining_accounts(vec![
-        ctx.accounts.const_account.to_account_info(),
-        ctx.accounts.additional_account.to_account_info(),
-        ctx.accounts.spot_market_sol.to_account_info(),
-        ctx.accounts.spot_market_usdc.to_account_info()
-    ]);
+    ).with_remaining_accounts(market_accounts);
 
     withdraw(cpi_ctx, DRIFT_MARKET_INDEX_SOL, amount, reduce_only)?;
 
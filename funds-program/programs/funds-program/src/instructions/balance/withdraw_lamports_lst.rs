@@ -0,0 +1,264 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{stake, sysvar};
+use anchor_spl::{
+    token,
+    token::{Mint, Token}, 
+    token::TokenAccount
+};
+use drift_cpi::{
+    cpi::withdraw, 
+    Withdraw
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_LST, DRIFT_PROGRAM_ID, LST_MINT_ADDRESS, STAKE_POOL_PROGRAM_ID}, 
+    errors::ErrorCode, 
+    stake_pool::{withdraw_sol, WithdrawSol},
+    state::Vault,
+    utils::{post_price_update, PythPriceUpdate}
+};
+
+#[derive(Accounts)]
+pub struct WithdrawLamportsLst<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [vault.key().as_ref(), lst_mint.key().as_ref()],
+        bump,
+        payer = owner,
+        token::mint = lst_mint,
+        token::authority = vault
+    )]
+    pub vault_lst: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which checks it against the pool mint
+    #[account(mut)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which performs the security checks
+    pub stake_pool_withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which performs the security checks
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the stake pool CPI, which performs the security checks
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = lst_mint.key() == LST_MINT_ADDRESS @ ErrorCode::InvalidMintAddress
+    )]
+    pub lst_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = stake_pool_program.key() == STAKE_POOL_PROGRAM_ID @ ErrorCode::InvalidStakePoolProgram
+    )]
+    pub stake_pool_program: UncheckedAccount<'info>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(address = sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", (DRIFT_MARKET_INDEX_LST).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        token::mint = lst_mint,
+        bump,
+    )]
+    pub spot_market_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub drift_signer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub lst_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_lst: UncheckedAccount<'info>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub pyth_solana_receiver: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub guardian_set: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    #[account(mut)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_lamports_lst_handler(
+    ctx: Context<WithdrawLamportsLst>, 
+    lst_amount: u64,
+    reduce_only: bool,
+    price_update: Option<PythPriceUpdate>
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    post_price_update(
+        price_update,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.drift_program.to_account_info(),
+        ctx.accounts.pyth_solana_receiver.as_ref(),
+        ctx.accounts.guardian_set.as_ref(),
+        ctx.accounts.price_feed.as_ref()
+    )?;
+
+    let market_accounts = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.lst_oracle.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info(),
+        ctx.accounts.spot_market_lst.to_account_info()
+    ];
+
+    ctx.accounts.vault.record_owner_withdraw(
+        &ctx.accounts.owner.key(),
+        DRIFT_MARKET_INDEX_LST,
+        lst_amount,
+        &market_accounts,
+        Clock::get()?.unix_timestamp
+    )?;
+
+    // Build Drift Withdraw CPI
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Withdraw {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            drift_signer: ctx.accounts.drift_signer.to_account_info(),
+            user_token_account: ctx.accounts.vault_lst.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    ).with_remaining_accounts(market_accounts);
+
+    withdraw(cpi_ctx, DRIFT_MARKET_INDEX_LST, lst_amount, reduce_only)?;
+
+    // Instant unstake from the pool reserve, sending SOL straight to the owner
+
+    ctx.accounts.vault_lst.reload()?;
+    let pool_tokens = ctx.accounts.vault_lst.amount;
+
+    withdraw_sol(
+        WithdrawSol {
+            stake_pool_program: ctx.accounts.stake_pool_program.to_account_info(),
+            stake_pool: ctx.accounts.stake_pool.to_account_info(),
+            withdraw_authority: ctx.accounts.stake_pool_withdraw_authority.to_account_info(),
+            user_transfer_authority: ctx.accounts.vault.to_account_info(),
+            pool_tokens_from: ctx.accounts.vault_lst.to_account_info(),
+            reserve_stake: ctx.accounts.reserve_stake.to_account_info(),
+            lamports_to: ctx.accounts.owner.to_account_info(),
+            manager_fee_account: ctx.accounts.manager_fee_account.to_account_info(),
+            pool_mint: ctx.accounts.lst_mint.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+            stake_history: ctx.accounts.stake_history.to_account_info(),
+            stake_program: ctx.accounts.stake_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info()
+        },
+        pool_tokens,
+        signer_seeds
+    )?;
+
+    // Close LST vault
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.vault_lst.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    token::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
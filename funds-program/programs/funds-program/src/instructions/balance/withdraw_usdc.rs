@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}
+    associated_token::AssociatedToken, 
+    token_interface::{self, Mint, TokenAccount, TokenInterface}
 };
 use drift_cpi::{
     cpi::withdraw, 
@@ -14,16 +15,18 @@ use drift_accounts::{
 use crate::{
     constants::{DRIFT_MARKET_INDEX_USDC, DRIFT_PROGRAM_ID, USDC_MINT_ADDRESS}, 
     errors::ErrorCode, 
-    state::Vault
+    state::Vault,
+    utils::{post_price_update, PythPriceUpdate}
 };
 
 #[derive(Accounts)]
 pub struct WithdrawUsdc<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -35,7 +38,7 @@ pub struct WithdrawUsdc<'info> {
         token::mint = usdc_mint,
         token::authority = vault
     )]
-    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+    pub vault_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -43,9 +46,10 @@ pub struct WithdrawUsdc<'info> {
     #[account(
         mut,
         associated_token::mint = usdc_mint,
-        associated_token::authority = owner
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
     )]
-    pub owner_usdc: Box<Account<'info, TokenAccount>>,
+    pub owner_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
@@ -78,7 +82,7 @@ pub struct WithdrawUsdc<'info> {
         token::mint = usdc_mint,
         bump,
     )]
-    pub spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     
     /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
     pub drift_signer: UncheckedAccount<'info>,
@@ -86,9 +90,9 @@ pub struct WithdrawUsdc<'info> {
     #[account(
         constraint = usdc_mint.key() == USDC_MINT_ADDRESS @ ErrorCode::InvalidMintAddress
     )]
-    pub usdc_mint: Box<Account<'info, Mint>>,
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
 
@@ -111,23 +115,62 @@ pub struct WithdrawUsdc<'info> {
     #[account(mut)]
     pub spot_market_usdc: UncheckedAccount<'info>,
 
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub pyth_solana_receiver: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub guardian_set: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    #[account(mut)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn withdraw_usdc_handler(
     ctx: Context<WithdrawUsdc>, 
     amount_micro_cents: u64,
-    reduce_only: bool
+    reduce_only: bool,
+    price_update: Option<PythPriceUpdate>
 ) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
     let vault_bump = ctx.accounts.vault.bump;
-    let owner = ctx.accounts.owner.key();
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
     let seeds = &[
         b"vault",
-        owner.as_ref(),
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
         &[vault_bump]
     ];
     let signer_seeds = &[&seeds[..]];
 
+    post_price_update(
+        price_update,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.drift_program.to_account_info(),
+        ctx.accounts.pyth_solana_receiver.as_ref(),
+        ctx.accounts.guardian_set.as_ref(),
+        ctx.accounts.price_feed.as_ref()
+    )?;
+
+    let market_accounts = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info(),
+    ];
+
+    ctx.accounts.vault.record_owner_withdraw(
+        &ctx.accounts.owner.key(),
+        DRIFT_MARKET_INDEX_USDC,
+        amount_micro_cents,
+        &market_accounts,
+        Clock::get()?.unix_timestamp
+    )?;
+
     // Build Drift Withdraw CPI
     let mut cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.drift_program.to_account_info(),
@@ -145,42 +188,39 @@ pub fn withdraw_usdc_handler(
     );
 
     // Add remaining accounts and send CPI
-    cpi_ctx.remaining_accounts = vec![
-        ctx.accounts.const_account.to_account_info(),
-        ctx.accounts.additional_account.to_account_info(),
-        ctx.accounts.spot_market_sol.to_account_info(),
-        ctx.accounts.spot_market_usdc.to_account_info(),
-    ];
+    cpi_ctx.remaining_accounts = market_accounts;
 
     withdraw(cpi_ctx, DRIFT_MARKET_INDEX_USDC, amount_micro_cents, reduce_only)?;
 
     // Transfer USDC to owner's ATA
 
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(), 
-            token::Transfer { 
+            token_interface::TransferChecked { 
                 from: ctx.accounts.vault_usdc.to_account_info(), 
+                mint: ctx.accounts.usdc_mint.to_account_info(),
                 to: ctx.accounts.owner_usdc.to_account_info(), 
                 authority: ctx.accounts.vault.to_account_info()
             }, 
             signer_seeds
         ),
-        amount_micro_cents
+        amount_micro_cents,
+        ctx.accounts.usdc_mint.decimals
     )?;
 
     // Close vault USDC
 
     let cpi_ctx_close = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        token::CloseAccount {
+        token_interface::CloseAccount {
             account: ctx.accounts.vault_usdc.to_account_info(),
             destination: ctx.accounts.owner_usdc.to_account_info(),
             authority: ctx.accounts.vault.to_account_info(),
         },
         signer_seeds
     );
-    token::close_account(cpi_ctx_close)?;
+    token_interface::close_account(cpi_ctx_close)?;
 
     Ok(())
 }
\ No newline at end of file
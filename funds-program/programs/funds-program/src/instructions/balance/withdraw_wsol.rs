@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}
+};
+use drift_cpi::{
+    cpi::withdraw, 
+    Withdraw
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_SOL, DRIFT_PROGRAM_ID, WSOL_MINT_ADDRESS}, 
+    errors::ErrorCode, 
+    state::Vault,
+    utils::{post_price_update, PythPriceUpdate}
+};
+
+#[derive(Accounts)]
+pub struct WithdrawWsol<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [vault.key().as_ref(), wsol_mint.key().as_ref()],
+        bump,
+        payer = owner,
+        token::mint = wsol_mint,
+        token::authority = vault
+    )]
+    pub vault_wsol: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_wsol: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", (DRIFT_MARKET_INDEX_SOL).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        token::mint = wsol_mint,
+        bump,
+    )]
+    pub spot_market_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub drift_signer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = wsol_mint.key() == WSOL_MINT_ADDRESS @ ErrorCode::InvalidMintAddress
+    )]
+    pub wsol_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub pyth_solana_receiver: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub guardian_set: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    #[account(mut)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_wsol_handler(
+    ctx: Context<WithdrawWsol>, 
+    amount: u64,
+    reduce_only: bool,
+    price_update: Option<PythPriceUpdate>
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    post_price_update(
+        price_update,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.drift_program.to_account_info(),
+        ctx.accounts.pyth_solana_receiver.as_ref(),
+        ctx.accounts.guardian_set.as_ref(),
+        ctx.accounts.price_feed.as_ref()
+    )?;
+
+    let market_accounts = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info(),
+    ];
+
+    ctx.accounts.vault.record_owner_withdraw(
+        &ctx.accounts.owner.key(),
+        DRIFT_MARKET_INDEX_SOL,
+        amount,
+        &market_accounts,
+        Clock::get()?.unix_timestamp
+    )?;
+
+    // Build Drift Withdraw CPI
+    let mut cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Withdraw {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            drift_signer: ctx.accounts.drift_signer.to_account_info(),
+            user_token_account: ctx.accounts.vault_wsol.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    // Add remaining accounts and send CPI
+    cpi_ctx.remaining_accounts = market_accounts;
+
+    withdraw(cpi_ctx, DRIFT_MARKET_INDEX_SOL, amount, reduce_only)?;
+
+    // Transfer wSOL to owner's ATA without unwrapping
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(), 
+            token::Transfer { 
+                from: ctx.accounts.vault_wsol.to_account_info(), 
+                to: ctx.accounts.owner_wsol.to_account_info(), 
+                authority: ctx.accounts.vault.to_account_info()
+            }, 
+            signer_seeds
+        ),
+        amount
+    )?;
+
+    // Close vault wSOL
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.vault_wsol.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    token::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+mod issue_card;
+pub use issue_card::*;
+
+mod freeze_card;
+pub use freeze_card::*;
+
+mod unfreeze_card;
+pub use unfreeze_card::*;
+
+mod cancel_card;
+pub use cancel_card::*;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Card, CardStatus, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct CancelCard<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"card", vault.key().as_ref(), card.card_id.to_le_bytes().as_ref()],
+        bump = card.bump,
+        has_one = vault
+    )]
+    pub card: Box<Account<'info, Card>>,
+
+    pub owner: Signer<'info>
+}
+
+pub fn cancel_card_handler(ctx: Context<CancelCard>) -> Result<()> {
+    // Cancelling is permanent, the card PDA stays so its ID can't be reissued
+    require!(ctx.accounts.card.status != CardStatus::Cancelled, ErrorCode::CardCancelled);
+
+    ctx.accounts.card.status = CardStatus::Cancelled;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
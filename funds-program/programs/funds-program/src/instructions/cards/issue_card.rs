@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Card, CardStatus, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct IssueCard<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"card", vault.key().as_ref(), card_id.to_le_bytes().as_ref()],
+        bump,
+        payer = owner,
+        space = Card::INIT_SPACE
+    )]
+    pub card: Box<Account<'info, Card>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn issue_card_handler(
+    ctx: Context<IssueCard>,
+    card_id: u64,
+    limit_usd: u64,
+    period_seconds: i64,
+    expiry_ts: i64
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    require!(limit_usd > 0 && period_seconds >= 0, ErrorCode::InvalidCardLimit);
+    require!(expiry_ts > current_ts, ErrorCode::CardExpired);
+
+    let card = &mut ctx.accounts.card;
+    card.vault = ctx.accounts.vault.key();
+    card.card_id = card_id;
+    card.status = CardStatus::Active;
+    card.limit_usd = limit_usd;
+    card.spent_in_period_usd = 0;
+    card.period_seconds = period_seconds;
+    card.period_start_ts = current_ts;
+    card.expiry_ts = expiry_ts;
+    card.bump = ctx.bumps.card;
+
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
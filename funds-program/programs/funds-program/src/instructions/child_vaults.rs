@@ -0,0 +1,17 @@
+mod create_child_vault;
+pub use create_child_vault::*;
+
+mod set_child_limits;
+pub use set_child_limits::*;
+
+mod freeze_child_vault;
+pub use freeze_child_vault::*;
+
+mod unfreeze_child_vault;
+pub use unfreeze_child_vault::*;
+
+mod schedule_child_top_up;
+pub use schedule_child_top_up::*;
+
+mod top_up_child_vault;
+pub use top_up_child_vault::*;
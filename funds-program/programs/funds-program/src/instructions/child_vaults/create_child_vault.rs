@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::{initialize_user, initialize_user_stats}, InitializeUser, InitializeUserStats
+};
+use drift_accounts::State as DriftState;
+use crate::{
+    state::{ChildTopUp, OwnerRegistry, Vault},
+    errors::ErrorCode,
+    constants::{DRIFT_PROGRAM_ID, MAX_VAULTS_PER_OWNER}
+};
+
+#[derive(Accounts)]
+#[instruction(child_owner: Pubkey, child_vault_id: u16)]
+pub struct CreateChildVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"vault", child_owner.as_ref(), Vault::id_seed(child_vault_id).as_ref()],
+        bump,
+        payer = owner,
+        space = Vault::INIT_SPACE
+    )]
+    pub child_vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"child_top_up", child_vault.key().as_ref()],
+        bump,
+        payer = owner,
+        space = ChildTopUp::INIT_SPACE
+    )]
+    pub child_top_up: Box<Account<'info, ChildTopUp>>,
+
+    // Only needed if the child owner has created a registry
+    #[account(
+        mut,
+        seeds = [b"owner_registry", child_owner.as_ref()],
+        bump = child_owner_registry.bump,
+        constraint = child_owner_registry.owner == child_owner @ ErrorCode::InvalidChildOwner
+    )]
+    pub child_owner_registry: Option<Account<'info, OwnerRegistry>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(
+        mut,
+        seeds = [b"user", child_vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub child_drift_user: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(
+        mut,
+        seeds = [b"user_stats", child_vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub child_drift_user_stats: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    
+    pub system_program: Program<'info, System>
+}
+
+// Creates a vault owned by the child, at the address init_user or init_vault would give it, linked to the parent
+pub fn create_child_vault_handler(
+    ctx: Context<CreateChildVault>,
+    child_owner: Pubkey,
+    child_vault_id: u16,
+    name: [u8; 32],
+    limit_usd: u64,
+    period_seconds: i64
+) -> Result<()> {
+    require!(!ctx.accounts.vault.is_owner(&child_owner), ErrorCode::InvalidChildOwner);
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    let parent_vault = ctx.accounts.vault.key();
+    let child_vault_key = ctx.accounts.child_vault.key();
+
+    let child_vault = &mut ctx.accounts.child_vault;
+    child_vault.set_inner(Vault::new(child_owner, ctx.bumps.child_vault, child_owner, child_vault_id));
+    child_vault.parent_vault = Some(parent_vault);
+    child_vault.set_spend_limit(limit_usd, period_seconds, current_ts)?;
+    child_vault.last_active_ts = current_ts;
+
+    ctx.accounts.child_top_up.set_inner(ChildTopUp {
+        parent_vault,
+        child_vault: child_vault_key,
+        market_index: 0,
+        amount: 0,
+        interval_seconds: 0,
+        next_top_up_ts: 0,
+        bump: ctx.bumps.child_top_up
+    });
+
+    if let Some(owner_registry) = &mut ctx.accounts.child_owner_registry {
+        if !owner_registry.vault_ids.contains(&child_vault_id) {
+            require!(owner_registry.vault_ids.len() < MAX_VAULTS_PER_OWNER, ErrorCode::TooManyVaults);
+            owner_registry.vault_ids.push(child_vault_id);
+        }
+    }
+
+    ctx.accounts.vault.record_activity()?;
+
+    let child_bump = ctx.accounts.child_vault.bump;
+    let child_vault_id_seed = Vault::id_seed(child_vault_id);
+    let seeds = &[
+        b"vault",
+        child_owner.as_ref(),
+        child_vault_id_seed.as_ref(),
+        &[child_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Initialize the child's own Drift account, paid for by the parent
+
+    let create_user_stats_cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        InitializeUserStats {
+            user_stats: ctx.accounts.child_drift_user_stats.to_account_info(),
+            state: ctx.accounts.drift_state.to_account_info(),
+            authority: ctx.accounts.child_vault.to_account_info(),
+            payer: ctx.accounts.owner.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        signer_seeds
+    );
+    
+    initialize_user_stats(create_user_stats_cpi_context)?;
+
+    let create_user_cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        InitializeUser {
+            user: ctx.accounts.child_drift_user.to_account_info(),
+            user_stats: ctx.accounts.child_drift_user_stats.to_account_info(),
+            state: ctx.accounts.drift_state.to_account_info(),
+            authority: ctx.accounts.child_vault.to_account_info(),
+            payer: ctx.accounts.owner.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    initialize_user(create_user_cpi_context, 0, name)?;
+
+    Ok(())
+}
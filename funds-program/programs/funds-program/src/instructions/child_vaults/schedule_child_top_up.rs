@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    state::{ChildTopUp, Vault},
+    errors::ErrorCode,
+    utils::get_drift_market_index
+};
+
+#[derive(Accounts)]
+pub struct ScheduleChildTopUp<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [b"vault", child_vault.owner_seed.as_ref(), child_vault.vault_id_seed().as_ref()],
+        bump = child_vault.bump,
+        constraint = child_vault.is_child_of(&vault.key()) @ ErrorCode::NotAChildVault
+    )]
+    pub child_vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"child_top_up", child_vault.key().as_ref()],
+        bump = child_top_up.bump
+    )]
+    pub child_top_up: Box<Account<'info, ChildTopUp>>,
+
+    pub owner: Signer<'info>,
+
+    pub mint: Box<Account<'info, Mint>>
+}
+
+// A zero interval cancels the schedule
+pub fn schedule_child_top_up_handler(
+    ctx: Context<ScheduleChildTopUp>,
+    amount: u64,
+    interval_seconds: i64,
+    first_top_up_ts: i64
+) -> Result<()> {
+    require!(
+        interval_seconds == 0 || (amount > 0 && interval_seconds > 0),
+        ErrorCode::InvalidChildTopUp
+    );
+
+    let market_index = get_drift_market_index(&ctx.accounts.mint.key())?;
+
+    let child_top_up = &mut ctx.accounts.child_top_up;
+    child_top_up.market_index = market_index;
+    child_top_up.amount = amount;
+    child_top_up.interval_seconds = interval_seconds;
+    child_top_up.next_top_up_ts = first_top_up_ts;
+
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
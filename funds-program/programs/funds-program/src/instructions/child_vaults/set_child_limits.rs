@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct SetChildLimits<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", child_vault.owner_seed.as_ref(), child_vault.vault_id_seed().as_ref()],
+        bump = child_vault.bump,
+        constraint = child_vault.is_child_of(&vault.key()) @ ErrorCode::NotAChildVault
+    )]
+    pub child_vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>
+}
+
+// The child's spend limit covers its cards, allowances and payments, a zero limit removes it
+pub fn set_child_limits_handler(
+    ctx: Context<SetChildLimits>,
+    limit_usd: u64,
+    period_seconds: i64
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.child_vault.set_spend_limit(limit_usd, period_seconds, current_ts)?;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
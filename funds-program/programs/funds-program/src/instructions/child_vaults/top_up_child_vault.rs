@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token};
+use drift_cpi::{
+    cpi::{deposit, withdraw}, 
+    Deposit,
+    Withdraw
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_SOL, DRIFT_PROGRAM_ID}, 
+    errors::ErrorCode, 
+    state::{ChildTopUp, Vault},
+    utils::{get_drift_market_index, get_spot_deposit_amount, get_token_account_amount, init_vault_token_account, load_spot_market}
+};
+
+#[derive(Accounts)]
+pub struct TopUpChildVault<'info> {
+    #[account(
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [b"vault", child_vault.owner_seed.as_ref(), child_vault.vault_id_seed().as_ref()],
+        bump = child_vault.bump,
+        constraint = child_vault.is_child_of(&vault.key()) @ ErrorCode::NotAChildVault
+    )]
+    pub child_vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"child_top_up", child_vault.key().as_ref()],
+        bump = child_top_up.bump
+    )]
+    pub child_top_up: Box<Account<'info, ChildTopUp>>,
+
+    /// CHECK: Created and closed within the instruction, init_vault_token_account checks the address
+    #[account(mut)]
+    pub child_spl: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+
+    #[account(
+        mut,
+        seeds = [b"user", child_vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub child_drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", child_vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub child_drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", child_top_up.market_index.to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump,
+    )]
+    pub spot_market_vault: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub drift_signer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = get_drift_market_index(&mint.key())? == child_top_up.market_index @ ErrorCode::InvalidMarketIndex
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+// Permissionless crank for the parent's scheduled top-ups. The vaults are separate Drift authorities,
+// so the parent withdraws into the child's token account and the child deposits it
+pub fn top_up_child_vault_handler(ctx: Context<TopUpChildVault>) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let child_top_up = &mut ctx.accounts.child_top_up;
+
+    require!(child_top_up.interval_seconds > 0, ErrorCode::InvalidChildTopUp);
+    require!(current_ts >= child_top_up.next_top_up_ts, ErrorCode::ChildTopUpNotDue);
+
+    // Missed top-ups are caught up one interval per crank
+    child_top_up.next_top_up_ts += child_top_up.interval_seconds;
+
+    let market_index = child_top_up.market_index;
+    let amount = child_top_up.amount;
+
+    // Drift would let the withdraw open a borrow, so top-ups are limited to the parent's deposits
+    {
+        let spot_market = if market_index == DRIFT_MARKET_INDEX_SOL {
+            load_spot_market(&ctx.accounts.spot_market_sol.to_account_info())?
+        } else {
+            load_spot_market(&ctx.accounts.spot_market_usdc.to_account_info())?
+        };
+        require!(spot_market.market_index == market_index, ErrorCode::InvalidMarketIndex);
+
+        let drift_user = ctx.accounts.drift_user.load()
+            .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+        require!(
+            get_spot_deposit_amount(&drift_user, &spot_market)? >= amount,
+            ErrorCode::ChildTopUpExceedsDeposit
+        );
+    }
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let child_bump = ctx.accounts.child_vault.bump;
+    let child_owner_seed = ctx.accounts.child_vault.owner_seed;
+    let child_vault_id_seed = ctx.accounts.child_vault.vault_id_seed();
+    let child_seeds = &[
+        b"vault",
+        child_owner_seed.as_ref(),
+        child_vault_id_seed.as_ref(),
+        &[child_bump]
+    ];
+    let child_signer_seeds = &[&child_seeds[..]];
+
+    let market_accounts = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info()
+    ];
+
+    init_vault_token_account(
+        &ctx.accounts.child_spl.to_account_info(),
+        &ctx.accounts.child_vault.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.cranker.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info()
+    )?;
+
+    // Withdraw from the parent's Drift account into the child's token account
+
+    let withdraw_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Withdraw {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            drift_signer: ctx.accounts.drift_signer.to_account_info(),
+            user_token_account: ctx.accounts.child_spl.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    ).with_remaining_accounts(market_accounts.clone());
+
+    withdraw(withdraw_cpi_ctx, market_index, amount, false)?;
+
+    // Deposit what arrived into the child's Drift account
+
+    let received_amount = get_token_account_amount(&ctx.accounts.child_spl.to_account_info())?;
+
+    let deposit_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Deposit {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.child_drift_user.to_account_info(),
+            user_stats: ctx.accounts.child_drift_user_stats.to_account_info(),
+            authority: ctx.accounts.child_vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            user_token_account: ctx.accounts.child_spl.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        child_signer_seeds
+    ).with_remaining_accounts(market_accounts);
+
+    deposit(deposit_cpi_ctx, market_index, received_amount, false)?;
+
+    // Close the child's token account, returning rent to the cranker
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.child_spl.to_account_info(),
+            destination: ctx.accounts.cranker.to_account_info(),
+            authority: ctx.accounts.child_vault.to_account_info(),
+        },
+        child_signer_seeds
+    );
+    token::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct UnfreezeChildVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", child_vault.owner_seed.as_ref(), child_vault.vault_id_seed().as_ref()],
+        bump = child_vault.bump,
+        constraint = child_vault.is_child_of(&vault.key()) @ ErrorCode::NotAChildVault
+    )]
+    pub child_vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>
+}
+
+pub fn unfreeze_child_vault_handler(ctx: Context<UnfreezeChildVault>) -> Result<()> {
+    require!(ctx.accounts.child_vault.paused, ErrorCode::ChildVaultNotFrozen);
+
+    ctx.accounts.child_vault.paused = false;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
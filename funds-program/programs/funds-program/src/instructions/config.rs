@@ -1,6 +1,18 @@
 mod init_user;
 pub use init_user::*;
 
+mod init_owner_registry;
+pub use init_owner_registry::*;
+
+mod init_vault;
+pub use init_vault::*;
+
+mod migrate_vault;
+pub use migrate_vault::*;
+
+mod init_joint_vault;
+pub use init_joint_vault::*;
+
 mod close_user;
 pub use close_user::*;
 
@@ -9,3 +21,21 @@ pub use init_drift_account::*;
 
 mod close_drift_account;
 pub use close_drift_account::*;
+
+mod set_drift_delegate;
+pub use set_drift_delegate::*;
+
+mod clear_drift_delegate;
+pub use clear_drift_delegate::*;
+
+mod set_custom_margin_ratio;
+pub use set_custom_margin_ratio::*;
+
+mod set_margin_trading_enabled;
+pub use set_margin_trading_enabled::*;
+
+mod set_lst_mode;
+pub use set_lst_mode::*;
+
+mod set_co_owners;
+pub use set_co_owners::*;
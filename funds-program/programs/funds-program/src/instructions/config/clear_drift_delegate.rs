@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::update_user_delegate, 
+    UpdateUserDelegate
+};
+use drift_accounts::User as DriftUser;
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::DRIFT_PROGRAM_ID
+};
+
+#[derive(Accounts)]
+pub struct ClearDriftDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+pub fn clear_drift_delegate_handler(
+    ctx: Context<ClearDriftDelegate>
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        UpdateUserDelegate {
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    );
+
+    update_user_delegate(cpi_ctx, 0, Pubkey::default())?;
+
+    ctx.accounts.vault.drift_delegate = None;
+
+    Ok(())
+}
@@ -14,9 +14,9 @@ use crate::{
 pub struct CloseDriftAccount<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -59,11 +59,16 @@ pub struct CloseDriftAccount<'info> {
 pub fn close_drift_account_handler(
     ctx: Context<CloseDriftAccount>
 ) -> Result<()> {    
+    ctx.accounts.vault.require_owner_threshold(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    ctx.accounts.vault.record_activity()?;
+
     let vault_bump = ctx.accounts.vault.bump;
-    let owner = ctx.accounts.owner.key();
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
     let seeds = &[
         b"vault",
-        owner.as_ref(),
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
         &[vault_bump]
     ];
     let signer_seeds = &[&seeds[..]];
@@ -1,21 +1,40 @@
 use anchor_lang::prelude::*;
-use crate::state::Vault;
+use crate::{
+    state::{OwnerRegistry, Vault},
+    errors::ErrorCode
+};
 
 #[derive(Accounts)]
 pub struct CloseUser<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
         bump = vault.bump,
-        has_one = owner,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault,
         close = owner,
     )]
     pub vault: Box<Account<'info, Vault>>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
+
+    // Only needed if the vault is listed in its creator's registry
+    #[account(
+        mut,
+        seeds = [b"owner_registry", vault.owner_seed.as_ref()],
+        bump = owner_registry.bump
+    )]
+    pub owner_registry: Option<Account<'info, OwnerRegistry>>,
 }
 
-pub fn close_user_handler(_ctx: Context<CloseUser>) -> Result<()> {
+pub fn close_user_handler(ctx: Context<CloseUser>) -> Result<()> {
+    ctx.accounts.vault.require_owner_threshold(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    let vault_id = ctx.accounts.vault.vault_id;
+    if let Some(owner_registry) = &mut ctx.accounts.owner_registry {
+        owner_registry.vault_ids.retain(|id| *id != vault_id);
+    }
+
     Ok(())
 }
\ No newline at end of file
@@ -6,16 +6,16 @@ use drift_accounts::State as DriftState;
 use crate::{
     state::Vault,
     errors::ErrorCode,
-    constants::DRIFT_PROGRAM_ID
+    constants::{DRIFT_PROGRAM_ID, QUARTZ_REFERRER}
 };
 
 #[derive(Accounts)]
 pub struct InitDriftAccount<'info> {
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
         bump = vault.bump,
-        has_one = owner
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
     )]
     pub vault: Box<Account<'info, Vault>>,
 
@@ -54,19 +54,50 @@ pub struct InitDriftAccount<'info> {
     )]
     pub drift_program: UncheckedAccount<'info>,
 
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub referrer_user: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub referrer_user_stats: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Default referrer, passed through to the Drift CPI when no other referrer is given
+    #[account(
+        mut,
+        seeds = [b"user", QUARTZ_REFERRER.as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub quartz_referrer_user: UncheckedAccount<'info>,
+
+    /// CHECK: Default referrer, passed through to the Drift CPI when no other referrer is given
+    #[account(
+        mut,
+        seeds = [b"user_stats", QUARTZ_REFERRER.as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub quartz_referrer_user_stats: UncheckedAccount<'info>,
+
     pub rent: Sysvar<'info, Rent>,
     
     pub system_program: Program<'info, System>
 }
 
 pub fn init_drift_account_handler(
-    ctx: Context<InitDriftAccount>
+    ctx: Context<InitDriftAccount>,
+    name: [u8; 32]
 ) -> Result<()> {    
+    ctx.accounts.vault.record_activity()?;
+
     let vault_bump = ctx.accounts.vault.bump;
-    let owner = ctx.accounts.owner.key();
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
     let seeds = &[
         b"vault",
-        owner.as_ref(),
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
         &[vault_bump]
     ];
     let signer_seeds = &[&seeds[..]];
@@ -90,7 +121,7 @@ pub fn init_drift_account_handler(
 
     // Initialize user account
 
-    let create_user_cpi_context = CpiContext::new_with_signer(
+    let mut create_user_cpi_context = CpiContext::new_with_signer(
         ctx.accounts.drift_program.to_account_info(),
         InitializeUser {
             user: ctx.accounts.drift_user.to_account_info(),
@@ -103,8 +134,28 @@ pub fn init_drift_account_handler(
         },
         signer_seeds
     );
+
+    // Drift reads the referrer's User and UserStats from the remaining accounts.
+    // Without a referrer from the client, Quartz's is used on clusters where it has a Drift account.
+    match (&ctx.accounts.referrer_user, &ctx.accounts.referrer_user_stats) {
+        (Some(referrer_user), Some(referrer_user_stats)) => {
+            create_user_cpi_context.remaining_accounts = vec![
+                referrer_user.to_account_info(),
+                referrer_user_stats.to_account_info(),
+            ];
+        },
+        (None, None) => {
+            if !ctx.accounts.quartz_referrer_user.data_is_empty() {
+                create_user_cpi_context.remaining_accounts = vec![
+                    ctx.accounts.quartz_referrer_user.to_account_info(),
+                    ctx.accounts.quartz_referrer_user_stats.to_account_info(),
+                ];
+            }
+        },
+        _ => return err!(ErrorCode::InvalidReferrer)
+    }
  
-    initialize_user(create_user_cpi_context, 0, [0; 32])?;
+    initialize_user(create_user_cpi_context, 0, name)?;
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct InitJointVault<'info> {
+    #[account(
+        init,
+        seeds = [b"vault", vault_seed.key().as_ref()],
+        bump,
+        payer = owner,
+        space = Vault::INIT_SPACE
+    )]
+    pub vault: Account<'info, Vault>,
+
+    // Throwaway keypair, so the vault address isn't tied to any one co-owner's wallet
+    pub vault_seed: Signer<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn init_joint_vault_handler(
+    ctx: Context<InitJointVault>,
+    co_owners: Vec<Pubkey>,
+    threshold: u8
+) -> Result<()> {
+    require!(!co_owners.is_empty(), ErrorCode::InvalidCoOwner);
+
+    ctx.accounts.vault.set_inner(Vault::new(
+        ctx.accounts.owner.key(),
+        ctx.bumps.vault,
+        ctx.accounts.vault_seed.key(),
+        0
+    ));
+    ctx.accounts.vault.set_co_owners(co_owners, threshold)?;
+    ctx.accounts.vault.record_activity()?;
+    Ok(())
+}
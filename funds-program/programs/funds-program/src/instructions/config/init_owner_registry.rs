@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::OwnerRegistry;
+
+#[derive(Accounts)]
+pub struct InitOwnerRegistry<'info> {
+    #[account(
+        init,
+        seeds = [b"owner_registry", owner.key().as_ref()],
+        bump,
+        payer = owner,
+        space = OwnerRegistry::INIT_SPACE
+    )]
+    pub owner_registry: Account<'info, OwnerRegistry>,
+
+    /// CHECK: Only checked for existing data, so vault 0 can be listed if the owner already has one
+    #[account(
+        seeds = [b"vault", owner.key().as_ref()],
+        bump
+    )]
+    pub first_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn init_owner_registry_handler(ctx: Context<InitOwnerRegistry>) -> Result<()> {
+    let owner_registry = &mut ctx.accounts.owner_registry;
+    owner_registry.owner = ctx.accounts.owner.key();
+    owner_registry.vault_ids = Vec::new();
+    owner_registry.bump = ctx.bumps.owner_registry;
+
+    if !ctx.accounts.first_vault.data_is_empty() {
+        owner_registry.vault_ids.push(0);
+    }
+
+    Ok(())
+}
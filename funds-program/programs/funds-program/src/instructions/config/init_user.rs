@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::Vault;
+use crate::{
+    state::{OwnerRegistry, Vault},
+    errors::ErrorCode,
+    constants::MAX_VAULTS_PER_OWNER
+};
 
 #[derive(Accounts)]
 pub struct InitializeUser<'info> {
@@ -15,11 +19,29 @@ pub struct InitializeUser<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    // Only needed if the owner created a registry before their first vault
+    #[account(
+        mut,
+        seeds = [b"owner_registry", owner.key().as_ref()],
+        bump = owner_registry.bump,
+        has_one = owner
+    )]
+    pub owner_registry: Option<Account<'info, OwnerRegistry>>,
+
     pub system_program: Program<'info, System>
 }
 
 pub fn init_user_handler(ctx: Context<InitializeUser>) -> Result<()> {
-    ctx.accounts.vault.owner = ctx.accounts.owner.key();
-    ctx.accounts.vault.bump = ctx.bumps.vault;
+    let owner = ctx.accounts.owner.key();
+    ctx.accounts.vault.set_inner(Vault::new(owner, ctx.bumps.vault, owner, 0));
+    ctx.accounts.vault.record_activity()?;
+
+    if let Some(owner_registry) = &mut ctx.accounts.owner_registry {
+        if !owner_registry.vault_ids.contains(&0) {
+            require!(owner_registry.vault_ids.len() < MAX_VAULTS_PER_OWNER, ErrorCode::TooManyVaults);
+            owner_registry.vault_ids.push(0);
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{OwnerRegistry, Vault},
+    errors::ErrorCode,
+    constants::MAX_VAULTS_PER_OWNER
+};
+
+#[derive(Accounts)]
+#[instruction(vault_id: u16)]
+pub struct InitVault<'info> {
+    #[account(
+        init,
+        seeds = [b"vault", owner.key().as_ref(), vault_id.to_le_bytes().as_ref()],
+        bump,
+        payer = owner,
+        space = Vault::INIT_SPACE
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"owner_registry", owner.key().as_ref()],
+        bump = owner_registry.bump,
+        has_one = owner
+    )]
+    pub owner_registry: Account<'info, OwnerRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+// Additional vaults for the same wallet, each with its own Drift account once init_drift_account is called
+pub fn init_vault_handler(ctx: Context<InitVault>, vault_id: u16) -> Result<()> {
+    // Vault 0 is created with init_user, at the address it had before vault IDs
+    require!(vault_id > 0, ErrorCode::InvalidVaultId);
+
+    // The ID can already be listed if the vault was closed without passing the registry
+    let owner_registry = &mut ctx.accounts.owner_registry;
+    if !owner_registry.vault_ids.contains(&vault_id) {
+        require!(owner_registry.vault_ids.len() < MAX_VAULTS_PER_OWNER, ErrorCode::TooManyVaults);
+        owner_registry.vault_ids.push(vault_id);
+    }
+
+    let owner = ctx.accounts.owner.key();
+    ctx.accounts.vault.set_inner(Vault::new(owner, ctx.bumps.vault, owner, vault_id));
+    ctx.accounts.vault.record_activity()?;
+    Ok(())
+}
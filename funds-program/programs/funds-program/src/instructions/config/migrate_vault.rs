@@ -0,0 +1,157 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator
+};
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::{ANCHOR_DISCRIMINATOR, LEGACY_VAULT_SIZE, VAULT_RESERVED_SIZE, VAULT_VERSION}
+};
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    /// CHECK: Can't be loaded as a Vault until migrated, the discriminator and seeds are checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID @ ErrorCode::InvalidQuartzAccount
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    // The owner or a sponsor, covering the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+// Version 0, from before versioning
+#[derive(AnchorDeserialize)]
+struct LegacyVault {
+    owner: Pubkey,
+    bump: u8
+}
+
+// Every past layout migrates to the one after it, so a vault on any version is brought up to date in one call
+enum VersionedVault {
+    Legacy(LegacyVault),
+    Current(Box<Vault>)
+}
+
+impl VersionedVault {
+    fn load(vault: &Pubkey, data: &[u8]) -> Result<Self> {
+        require!(data.len() >= ANCHOR_DISCRIMINATOR, ErrorCode::InvalidQuartzAccount);
+        require!(data[..ANCHOR_DISCRIMINATOR] == Vault::DISCRIMINATOR, ErrorCode::InvalidQuartzAccount);
+        let mut fields = &data[ANCHOR_DISCRIMINATOR..];
+
+        if data.len() == LEGACY_VAULT_SIZE {
+            let legacy_vault = LegacyVault::deserialize(&mut fields)?;
+            require!(
+                is_vault_address(vault, &legacy_vault.owner, 0, legacy_vault.bump),
+                ErrorCode::InvalidQuartzAccount
+            );
+            return Ok(Self::Legacy(legacy_vault));
+        }
+
+        let current_vault = Vault::deserialize(&mut fields).map_err(|_| ErrorCode::InvalidQuartzAccount)?;
+        require!(
+            current_vault.version == VAULT_VERSION
+                && is_vault_address(vault, &current_vault.owner_seed, current_vault.vault_id, current_vault.bump),
+            ErrorCode::InvalidQuartzAccount
+        );
+        Ok(Self::Current(Box::new(current_vault)))
+    }
+
+    fn migrate(self, current_ts: i64) -> Self {
+        match self {
+            // Legacy vaults were always the owner's first vault, with no other settings
+            Self::Legacy(legacy_vault) => Self::Current(Box::new(Vault {
+                version: VAULT_VERSION,
+                owner: legacy_vault.owner,
+                bump: legacy_vault.bump,
+                owner_seed: legacy_vault.owner,
+                guardians: Vec::new(),
+                guardian_threshold: 0,
+                beneficiary: None,
+                inactivity_period_seconds: 0,
+                last_active_ts: current_ts,
+                insurance_fund_unstake_request_ts: 0,
+                drift_delegate: None,
+                hedge_ratio_bps: 0,
+                hedge_keeper: None,
+                stop_loss: None,
+                lst_mode: false,
+                blocked_mccs: Vec::new(),
+                allowed_payees: Vec::new(),
+                category_caps: Vec::new(),
+                category_period_start_ts: 0,
+                paused: false,
+                spend_limit: None,
+                team_mode: false,
+                team_approval_threshold_usd: 0,
+                co_owners: Vec::new(),
+                co_owner_threshold: 0,
+                vault_id: 0,
+                parent_vault: None,
+                reserved: [0; VAULT_RESERVED_SIZE]
+            })),
+            Self::Current(vault) => Self::Current(vault)
+        }
+    }
+}
+
+fn is_vault_address(vault: &Pubkey, owner_seed: &Pubkey, vault_id: u16, bump: u8) -> bool {
+    let vault_id_seed = Vault::id_seed(vault_id);
+    Pubkey::create_program_address(
+        &[b"vault", owner_seed.as_ref(), vault_id_seed.as_ref(), &[bump]],
+        &crate::ID
+    ).is_ok_and(|address| address == *vault)
+}
+
+// Fills in defaults for new fields, so anyone can pay to migrate a vault without changing its behaviour
+pub fn migrate_vault_handler(ctx: Context<MigrateVault>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    let mut versioned_vault = {
+        let data = vault_info.try_borrow_data()?;
+        VersionedVault::load(vault_info.key, &data)?
+    };
+    require!(
+        !matches!(versioned_vault, VersionedVault::Current(_)), 
+        ErrorCode::VaultAlreadyMigrated
+    );
+
+    let vault = loop {
+        versioned_vault = versioned_vault.migrate(current_ts);
+        if let VersionedVault::Current(vault) = versioned_vault {
+            break vault;
+        }
+    };
+
+    let required_lamports = Rent::get()?.minimum_balance(Vault::INIT_SPACE);
+    let rent_difference = required_lamports.saturating_sub(vault_info.lamports());
+    if rent_difference > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: vault_info.clone()
+                }
+            ),
+            rent_difference
+        )?;
+    }
+
+    if vault_info.data_len() != Vault::INIT_SPACE {
+        vault_info.realloc(Vault::INIT_SPACE, true)?;
+    }
+
+    // Clear the old layout, fields past the new serialized length would otherwise be left behind
+    let mut data = vault_info.try_borrow_mut_data()?;
+    data.fill(0);
+    vault.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct SetCoOwners<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+}
+
+// Co-owners that must sign alongside the owner are passed as signers in the remaining accounts
+pub fn set_co_owners_handler(
+    ctx: Context<SetCoOwners>,
+    co_owners: Vec<Pubkey>,
+    threshold: u8
+) -> Result<()> {
+    ctx.accounts.vault.require_owner_threshold(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    ctx.accounts.vault.set_co_owners(co_owners, threshold)?;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
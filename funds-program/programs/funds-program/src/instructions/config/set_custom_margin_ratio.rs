@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::update_user_custom_margin_ratio, 
+    UpdateUserCustomMarginRatio
+};
+use drift_accounts::User as DriftUser;
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::{DRIFT_PROGRAM_ID, MAX_CUSTOM_MARGIN_RATIO}
+};
+
+#[derive(Accounts)]
+pub struct SetCustomMarginRatio<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+pub fn set_custom_margin_ratio_handler(
+    ctx: Context<SetCustomMarginRatio>,
+    margin_ratio: u32
+) -> Result<()> {
+    require!(margin_ratio <= MAX_CUSTOM_MARGIN_RATIO, ErrorCode::InvalidMarginRatio);
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        UpdateUserCustomMarginRatio {
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    );
+
+    // Drift uses the stricter of this and each market's own margin ratio, zero resets to the market default
+    update_user_custom_margin_ratio(cpi_ctx, 0, margin_ratio)?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct SetLstMode<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_lst_mode_handler(
+    ctx: Context<SetLstMode>,
+    enabled: bool
+) -> Result<()> {
+    // Existing SOL and LST balances on Drift are left untouched, only new deposits are affected
+    ctx.accounts.vault.lst_mode = enabled;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
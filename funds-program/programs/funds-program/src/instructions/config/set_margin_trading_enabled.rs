@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::update_user_margin_trading_enabled, 
+    UpdateUserMarginTradingEnabled
+};
+use drift_accounts::User as DriftUser;
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::DRIFT_PROGRAM_ID
+};
+
+#[derive(Accounts)]
+pub struct SetMarginTradingEnabled<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_usdc: UncheckedAccount<'info>
+}
+
+pub fn set_margin_trading_enabled_handler(
+    ctx: Context<SetMarginTradingEnabled>,
+    margin_trading_enabled: bool
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        UpdateUserMarginTradingEnabled {
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info()
+    ]);
+
+    // Drift checks the user's open spot positions against the markets passed in when disabling margin trading
+    update_user_margin_trading_enabled(cpi_ctx, 0, margin_trading_enabled)?;
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+mod set_hedge_mode;
+pub use set_hedge_mode::*;
+
+mod adjust_hedge;
+pub use adjust_hedge::*;
+
+mod settle_hedge_pnl;
+pub use settle_hedge_pnl::*;
+
+mod settle_hedge_funding;
+pub use settle_hedge_funding::*;
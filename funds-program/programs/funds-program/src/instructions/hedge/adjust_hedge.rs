@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::place_perp_order, 
+    PlacePerpOrder,
+    MarketType,
+    OrderParams,
+    OrderTriggerCondition,
+    OrderType,
+    PositionDirection,
+    PostOnlyParam
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::{
+        BPS_DENOMINATOR, DRIFT_MARKET_INDEX_LST, DRIFT_MARKET_INDEX_SOL, DRIFT_PERP_MARKET_INDEX_SOL, 
+        DRIFT_PROGRAM_ID, HEDGE_MAX_SLIPPAGE_BPS
+    }, 
+    errors::ErrorCode, 
+    price::{convert_at_price, get_spot_market_price},
+    state::Vault,
+    utils::{get_spot_deposit_amount, load_spot_market}
+};
+
+#[derive(Accounts)]
+pub struct AdjustHedge<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.can_manage_hedge(&authority.key()) @ ErrorCode::InvalidHedgeAuthority
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and discriminator are checked when it's loaded to size the hedge
+    #[account(
+        seeds = [b"spot_market", (DRIFT_MARKET_INDEX_SOL).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub perp_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and discriminator are checked when it's loaded, only needed if the vault holds the LST
+    #[account(
+        seeds = [b"spot_market", (DRIFT_MARKET_INDEX_LST).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub spot_market_lst: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked against the oracle set on the LST spot market, only needed if the vault holds the LST
+    pub oracle_lst: Option<UncheckedAccount<'info>>
+}
+
+pub fn adjust_hedge_handler(
+    ctx: Context<AdjustHedge>
+) -> Result<()> {
+    if ctx.accounts.vault.is_owner(&ctx.accounts.authority.key()) {
+        ctx.accounts.vault.record_activity()?;
+    }
+
+    let hedge_ratio_bps = ctx.accounts.vault.hedge_ratio_bps as u64;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    let mut oracles = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info()
+    ];
+    let mut spot_markets = vec![
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info()
+    ];
+    if let (Some(spot_market_lst), Some(oracle_lst)) = (&ctx.accounts.spot_market_lst, &ctx.accounts.oracle_lst) {
+        oracles.push(oracle_lst.to_account_info());
+        spot_markets.push(spot_market_lst.to_account_info());
+    }
+
+    let spot_market_sol = load_spot_market(&ctx.accounts.spot_market_sol.to_account_info())?;
+    let sol_price = get_spot_market_price(&spot_market_sol, &oracles, current_ts)?;
+
+    // Size the target short from the SOL and LST collateral, then compare against the position including open orders
+
+    let (sol_exposure, projected_base) = {
+        let drift_user = ctx.accounts.drift_user.load()
+            .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+
+        let sol_deposit = get_spot_deposit_amount(&drift_user, &spot_market_sol)?;
+
+        let holds_lst = drift_user.spot_positions.iter().any(|position| 
+            position.market_index == DRIFT_MARKET_INDEX_LST && position.scaled_balance > 0
+        );
+        let lst_exposure = match &ctx.accounts.spot_market_lst {
+            Some(spot_market_lst) => {
+                let spot_market_lst = load_spot_market(&spot_market_lst.to_account_info())?;
+                let lst_deposit = get_spot_deposit_amount(&drift_user, &spot_market_lst)?;
+                let lst_price = get_spot_market_price(&spot_market_lst, &oracles, current_ts)?;
+
+                // The LST and SOL share 9 decimals, so the LST converts to lamports at the ratio of their prices
+                convert_at_price(lst_deposit, lst_price, sol_price)?
+            },
+            None => {
+                // Leaving the LST out would under-hedge, and Drift's margin check needs its market anyway
+                require!(!holds_lst, ErrorCode::InvalidSpotMarket);
+                0
+            }
+        };
+
+        // Open orders could fill either way, so take whichever side leaves the larger position
+        let projected_base = drift_user.perp_positions.iter()
+            .filter(|position| position.market_index == DRIFT_PERP_MARKET_INDEX_SOL)
+            .try_fold(0i64, |total, position| {
+                let with_bids = position.base_asset_amount.checked_add(position.open_bids)?;
+                let with_asks = position.base_asset_amount.checked_add(position.open_asks)?;
+                let worst_case = if with_bids.unsigned_abs() >= with_asks.unsigned_abs() { with_bids } else { with_asks };
+                total.checked_add(worst_case)
+            })
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let sol_exposure = sol_deposit.checked_add(lst_exposure).ok_or(ErrorCode::MathOverflow)?;
+        (sol_exposure, projected_base)
+    };
+
+    // SOL and SOL-PERP share 9 decimals, so lamports map directly onto perp base units
+    let target_short = (sol_exposure as u128)
+        .checked_mul(hedge_ratio_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    let target_base = -i64::try_from(target_short).map_err(|_| ErrorCode::MathOverflow)?;
+
+    let delta = target_base.checked_sub(projected_base).ok_or(ErrorCode::MathOverflow)?;
+    if delta == 0 {
+        return Ok(());
+    }
+
+    // Shorts sell no lower, and buy-backs pay no higher, than the slippage allowance around the oracle price
+    let slippage = sol_price
+        .checked_mul(HEDGE_MAX_SLIPPAGE_BPS)
+        .ok_or(ErrorCode::MathOverflow)?
+        / BPS_DENOMINATOR;
+    let limit_price = if delta < 0 {
+        sol_price.checked_sub(slippage)
+    } else {
+        sol_price.checked_add(slippage)
+    }.ok_or(ErrorCode::MathOverflow)?;
+
+    let order_params = OrderParams {
+        order_type: OrderType::Market,
+        market_type: MarketType::Perp,
+        direction: if delta < 0 { PositionDirection::Short } else { PositionDirection::Long },
+        user_order_id: 0,
+        base_asset_amount: delta.unsigned_abs(),
+        price: limit_price,
+        market_index: DRIFT_PERP_MARKET_INDEX_SOL,
+        // Buying back can only shrink the short, never flip the vault long
+        reduce_only: delta > 0,
+        post_only: PostOnlyParam::None,
+        immediate_or_cancel: false,
+        max_ts: None,
+        trigger_price: None,
+        trigger_condition: OrderTriggerCondition::Above,
+        oracle_price_offset: None,
+        auction_duration: None,
+        auction_start_price: None,
+        auction_end_price: None
+    };
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        PlacePerpOrder {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(
+        oracles.into_iter()
+            .chain(spot_markets)
+            .chain([ctx.accounts.perp_market_sol.to_account_info()])
+            .collect()
+    );
+
+    place_perp_order(cpi_ctx, order_params)?;
+
+    Ok(())
+}
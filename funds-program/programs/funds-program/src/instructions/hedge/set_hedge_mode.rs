@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::BPS_DENOMINATOR
+};
+
+#[derive(Accounts)]
+pub struct SetHedgeMode<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_hedge_mode_handler(
+    ctx: Context<SetHedgeMode>,
+    hedge_ratio_bps: u16,
+    hedge_keeper: Option<Pubkey>
+) -> Result<()> {
+    require!(hedge_ratio_bps as u64 <= BPS_DENOMINATOR, ErrorCode::InvalidHedgeRatio);
+
+    // Disabling hedge mode leaves any open short in place until adjust_hedge buys it back
+    ctx.accounts.vault.hedge_ratio_bps = hedge_ratio_bps;
+    ctx.accounts.vault.hedge_keeper = hedge_keeper;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
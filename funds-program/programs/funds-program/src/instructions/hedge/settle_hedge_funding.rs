@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::settle_funding_payment, 
+    SettleFundingPayment
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::DRIFT_PROGRAM_ID, 
+    errors::ErrorCode, 
+    state::Vault
+};
+
+#[derive(Accounts)]
+pub struct SettleHedgeFunding<'info> {
+    #[account(
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub perp_market_sol: UncheckedAccount<'info>
+}
+
+pub fn settle_hedge_funding_handler(
+    ctx: Context<SettleHedgeFunding>
+) -> Result<()> {
+    // Funding settlement needs no authority on Drift, so anyone can crank it for the vault
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.drift_program.to_account_info(),
+        SettleFundingPayment {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info()
+        }
+    ).with_remaining_accounts(vec![
+        ctx.accounts.perp_market_sol.to_account_info()
+    ]);
+
+    settle_funding_payment(cpi_ctx)?;
+
+    Ok(())
+}
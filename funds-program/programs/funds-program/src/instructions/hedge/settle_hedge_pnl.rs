@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use drift_cpi::{
+    cpi::settle_pnl, 
+    SettlePnl
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_USDC, DRIFT_PERP_MARKET_INDEX_SOL, DRIFT_PROGRAM_ID}, 
+    errors::ErrorCode, 
+    state::Vault
+};
+
+#[derive(Accounts)]
+pub struct SettleHedgePnl<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.can_manage_hedge(&authority.key()) @ ErrorCode::InvalidHedgeAuthority
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    #[account(
+        seeds = [b"spot_market_vault", (DRIFT_MARKET_INDEX_USDC).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump,
+    )]
+    pub spot_market_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub perp_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks, only needed if the vault holds the LST
+    pub spot_market_lst: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks, only needed if the vault holds the LST
+    pub oracle_lst: Option<UncheckedAccount<'info>>
+}
+
+pub fn settle_hedge_pnl_handler(
+    ctx: Context<SettleHedgePnl>
+) -> Result<()> {
+    if ctx.accounts.vault.is_owner(&ctx.accounts.authority.key()) {
+        ctx.accounts.vault.record_activity()?;
+    }
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        SettlePnl {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(
+        [ctx.accounts.const_account.to_account_info(), ctx.accounts.additional_account.to_account_info()].into_iter()
+            .chain(ctx.accounts.oracle_lst.as_ref().map(|oracle| oracle.to_account_info()))
+            .chain([ctx.accounts.spot_market_usdc.to_account_info(), ctx.accounts.spot_market_sol.to_account_info()])
+            .chain(ctx.accounts.spot_market_lst.as_ref().map(|spot_market| spot_market.to_account_info()))
+            .chain([ctx.accounts.perp_market_sol.to_account_info()])
+            .collect()
+    );
+
+    // Realised PnL is moved into the vault's USDC spot balance on Drift
+    settle_pnl(cpi_ctx, DRIFT_PERP_MARKET_INDEX_SOL)?;
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod set_beneficiary;
+pub use set_beneficiary::*;
+
+mod claim_inheritance;
+pub use claim_inheritance::*;
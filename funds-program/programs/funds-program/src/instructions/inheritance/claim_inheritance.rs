@@ -0,0 +1,70 @@
+use anchor_lang::{prelude::*, system_program};
+use crate::{
+    state::{RecoveryRequest, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct ClaimInheritance<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.beneficiary == Some(beneficiary.key()) @ ErrorCode::InvalidBeneficiary
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Only checked for existing data, a pending recovery is closed so it can't be executed against the beneficiary
+    #[account(
+        mut,
+        seeds = [b"recovery", vault.key().as_ref()],
+        bump
+    )]
+    pub recovery_request: UncheckedAccount<'info>,
+
+    pub beneficiary: Signer<'info>,
+
+    /// CHECK: Only needed with a pending recovery, receives its rent and is checked against the request
+    #[account(mut)]
+    pub initiator: Option<UncheckedAccount<'info>>
+}
+
+pub fn claim_inheritance_handler(ctx: Context<ClaimInheritance>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    require!(
+        current_ts >= vault.last_active_ts.saturating_add(vault.inactivity_period_seconds),
+        ErrorCode::OwnerStillActive
+    );
+
+    // Beneficiary takes over the vault, and can then withdraw through the usual balance instructions.
+    // Anyone the previous owner gave control to is removed, the beneficiary can set their own
+    vault.owner = ctx.accounts.beneficiary.key();
+    vault.beneficiary = None;
+    vault.inactivity_period_seconds = 0;
+    vault.co_owners = Vec::new();
+    vault.co_owner_threshold = 0;
+    vault.guardians = Vec::new();
+    vault.guardian_threshold = 0;
+    vault.record_activity()?;
+
+    let recovery_request_info = ctx.accounts.recovery_request.to_account_info();
+    if !recovery_request_info.data_is_empty() {
+        let recovery_request = RecoveryRequest::try_deserialize(&mut &recovery_request_info.try_borrow_data()?[..])?;
+        let initiator = ctx.accounts.initiator.as_ref()
+            .ok_or(ErrorCode::InvalidRecoveryInitiator)?;
+        require_keys_eq!(initiator.key(), recovery_request.initiator, ErrorCode::InvalidRecoveryInitiator);
+
+        // Same as an Anchor close, the account can't be loaded as an Account with the handler's lifetimes
+        let initiator_info = initiator.to_account_info();
+        let initiator_lamports = initiator_info.lamports().checked_add(recovery_request_info.lamports())
+            .ok_or(ErrorCode::MathOverflow)?;
+        **initiator_info.try_borrow_mut_lamports()? = initiator_lamports;
+        **recovery_request_info.try_borrow_mut_lamports()? = 0;
+        recovery_request_info.assign(&system_program::ID);
+        recovery_request_info.realloc(0, false)?;
+    }
+
+    Ok(())
+}
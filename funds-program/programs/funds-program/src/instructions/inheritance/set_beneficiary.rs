@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::MIN_INACTIVITY_PERIOD_SECONDS
+};
+
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_beneficiary_handler(
+    ctx: Context<SetBeneficiary>,
+    beneficiary: Option<Pubkey>,
+    inactivity_period_seconds: i64
+) -> Result<()> {
+    ctx.accounts.vault.require_owner_threshold(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    if let Some(beneficiary) = beneficiary {
+        require!(!ctx.accounts.vault.is_owner(&beneficiary), ErrorCode::InvalidBeneficiary);
+        require!(
+            inactivity_period_seconds >= MIN_INACTIVITY_PERIOD_SECONDS,
+            ErrorCode::InactivityPeriodTooShort
+        );
+    }
+
+    ctx.accounts.vault.beneficiary = beneficiary;
+    ctx.accounts.vault.inactivity_period_seconds = inactivity_period_seconds;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
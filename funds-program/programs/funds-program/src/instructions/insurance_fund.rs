@@ -0,0 +1,14 @@
+mod init_insurance_fund_stake;
+pub use init_insurance_fund_stake::*;
+
+mod stake_insurance_fund;
+pub use stake_insurance_fund::*;
+
+mod request_unstake_insurance_fund;
+pub use request_unstake_insurance_fund::*;
+
+mod cancel_unstake_insurance_fund;
+pub use cancel_unstake_insurance_fund::*;
+
+mod unstake_insurance_fund;
+pub use unstake_insurance_fund::*;
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::initialize_insurance_fund_stake, 
+    InitializeInsuranceFundStake
+};
+use drift_accounts::State as DriftState;
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::{DRIFT_MARKET_INDEX_USDC, DRIFT_PROGRAM_ID}
+};
+
+#[derive(Accounts)]
+pub struct InitInsuranceFundStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(
+        mut,
+        seeds = [b"insurance_fund_stake", vault.key().as_ref(), (DRIFT_MARKET_INDEX_USDC).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub insurance_fund_stake: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    
+    pub system_program: Program<'info, System>
+}
+
+pub fn init_insurance_fund_stake_handler(
+    ctx: Context<InitInsuranceFundStake>
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        InitializeInsuranceFundStake {
+            spot_market: ctx.accounts.spot_market_usdc.to_account_info(),
+            insurance_fund_stake: ctx.accounts.insurance_fund_stake.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            state: ctx.accounts.drift_state.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            payer: ctx.accounts.owner.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    initialize_insurance_fund_stake(cpi_ctx, DRIFT_MARKET_INDEX_USDC)?;
+
+    Ok(())
+}
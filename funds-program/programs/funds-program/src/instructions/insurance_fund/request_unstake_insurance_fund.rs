@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use drift_cpi::{
+    cpi::request_remove_insurance_fund_stake, 
+    RequestRemoveInsuranceFundStake
+};
+use drift_accounts::UserStats as DriftUserStats;
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_USDC, DRIFT_PROGRAM_ID}, 
+    errors::ErrorCode, 
+    state::Vault
+};
+
+#[derive(Accounts)]
+pub struct RequestUnstakeInsuranceFund<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(
+        mut,
+        seeds = [b"insurance_fund_stake", vault.key().as_ref(), (DRIFT_MARKET_INDEX_USDC).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub insurance_fund_stake: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance_fund_vault", (DRIFT_MARKET_INDEX_USDC).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump,
+    )]
+    pub insurance_fund_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_usdc: UncheckedAccount<'info>
+}
+
+pub fn request_unstake_insurance_fund_handler(
+    ctx: Context<RequestUnstakeInsuranceFund>,
+    amount_micro_cents: u64
+) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        RequestRemoveInsuranceFundStake {
+            spot_market: ctx.accounts.spot_market_usdc.to_account_info(),
+            insurance_fund_stake: ctx.accounts.insurance_fund_stake.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            insurance_fund_vault: ctx.accounts.insurance_fund_vault.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    request_remove_insurance_fund_stake(cpi_ctx, DRIFT_MARKET_INDEX_USDC, amount_micro_cents)?;
+
+    // Drift only releases the stake once its unstaking period has passed since this request
+    ctx.accounts.vault.insurance_fund_unstake_request_ts = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+mod place_spot_order;
+pub use place_spot_order::*;
+
+mod modify_spot_order;
+pub use modify_spot_order::*;
+
+mod cancel_spot_order;
+pub use cancel_spot_order::*;
+
+mod cancel_spot_orders;
+pub use cancel_spot_orders::*;
+
+mod create_dca_plan;
+pub use create_dca_plan::*;
+
+mod cancel_dca_plan;
+pub use cancel_dca_plan::*;
+
+mod execute_dca_plan;
+pub use execute_dca_plan::*;
+
+mod set_stop_loss;
+pub use set_stop_loss::*;
+
+mod cancel_stop_loss;
+pub use cancel_stop_loss::*;
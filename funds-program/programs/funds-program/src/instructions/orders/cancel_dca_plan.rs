@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{DcaPlan, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct CancelDcaPlan<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"dca_plan", vault.key().as_ref(), dca_plan.plan_id.to_le_bytes().as_ref()],
+        bump = dca_plan.bump,
+        has_one = vault,
+        close = owner
+    )]
+    pub dca_plan: Box<Account<'info, DcaPlan>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>
+}
+
+pub fn cancel_dca_plan_handler(ctx: Context<CancelDcaPlan>) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::cancel_order, 
+    CancelOrder as DriftCancelOrder
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::DRIFT_PROGRAM_ID, 
+    errors::ErrorCode, 
+    state::Vault,
+    utils::validate_not_stop_loss_order
+};
+
+#[derive(Accounts)]
+pub struct CancelSpotOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+pub fn cancel_spot_order_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelSpotOrder<'info>>,
+    order_id: u32
+) -> Result<()> {
+    {
+        let drift_user = ctx.accounts.drift_user.load()
+            .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+        validate_not_stop_loss_order(&drift_user, order_id)?;
+    }
+
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        DriftCancelOrder {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+    cancel_order(cpi_ctx, Some(order_id))?;
+
+    Ok(())
+}
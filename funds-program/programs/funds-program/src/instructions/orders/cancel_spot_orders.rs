@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::cancel_orders_by_ids, 
+    CancelOrdersByIds as DriftCancelOrdersByIds
+};
+use drift_accounts::{
+    MarketType,
+    OrderStatus,
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::{DRIFT_PROGRAM_ID, STOP_LOSS_USER_ORDER_ID}, 
+    errors::ErrorCode, 
+    state::Vault
+};
+
+#[derive(Accounts)]
+pub struct CancelSpotOrders<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+pub fn cancel_spot_orders_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelSpotOrders<'info>>,
+    market_index: Option<u16>
+) -> Result<()> {
+    // Cancelled by id rather than by market so the stop-loss order is left in place
+    let order_ids: Vec<u32> = {
+        let drift_user = ctx.accounts.drift_user.load()
+            .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+        drift_user.orders.iter()
+            .filter(|order| 
+                order.status == OrderStatus::Open 
+                    && order.market_type == MarketType::Spot
+                    && order.user_order_id != STOP_LOSS_USER_ORDER_ID
+                    && (market_index.is_none() || market_index == Some(order.market_index))
+            )
+            .map(|order| order.order_id)
+            .collect()
+    };
+
+    ctx.accounts.vault.record_activity()?;
+
+    if order_ids.is_empty() {
+        return Ok(());
+    }
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        DriftCancelOrdersByIds {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+    cancel_orders_by_ids(cpi_ctx, order_ids)?;
+
+    Ok(())
+}
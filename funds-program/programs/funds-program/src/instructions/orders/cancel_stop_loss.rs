@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::cancel_order_by_user_id, 
+    CancelOrderByUserId
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::{DRIFT_PROGRAM_ID, STOP_LOSS_USER_ORDER_ID}, 
+    errors::ErrorCode, 
+    state::Vault,
+    utils::has_open_order_by_user_id
+};
+
+#[derive(Accounts)]
+pub struct CancelStopLoss<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = vault.stop_loss.is_some() @ ErrorCode::NoStopLoss
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks, only needed if the vault holds the LST or the stop-loss sells it
+    pub spot_market_lst: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks, only needed if the vault holds the LST or the stop-loss sells it
+    pub oracle_lst: Option<UncheckedAccount<'info>>
+}
+
+pub fn cancel_stop_loss_handler(
+    ctx: Context<CancelStopLoss>
+) -> Result<()> {
+    ctx.accounts.vault.stop_loss = None;
+    ctx.accounts.vault.record_activity()?;
+
+    // If keepers already triggered the order there's nothing left to cancel on Drift
+    let is_open = {
+        let drift_user = ctx.accounts.drift_user.load()
+            .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+        has_open_order_by_user_id(&drift_user, STOP_LOSS_USER_ORDER_ID)
+    };
+    if !is_open {
+        return Ok(());
+    }
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        CancelOrderByUserId {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(
+        [ctx.accounts.const_account.to_account_info(), ctx.accounts.additional_account.to_account_info()].into_iter()
+            .chain(ctx.accounts.oracle_lst.as_ref().map(|oracle| oracle.to_account_info()))
+            .chain([ctx.accounts.spot_market_sol.to_account_info(), ctx.accounts.spot_market_usdc.to_account_info()])
+            .chain(ctx.accounts.spot_market_lst.as_ref().map(|spot_market| spot_market.to_account_info()))
+            .collect()
+    );
+
+    cancel_order_by_user_id(cpi_ctx, STOP_LOSS_USER_ORDER_ID)?;
+
+    Ok(())
+}
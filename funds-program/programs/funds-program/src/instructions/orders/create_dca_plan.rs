@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{DcaPlan, OrderSide, Vault},
+    errors::ErrorCode,
+    utils::validate_spot_order_market
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DcaPlanParams {
+    pub market_index: u16,
+    pub side: OrderSide,
+    pub amount_usd: u64,
+    pub max_base_asset_amount: u64,
+    pub limit_price: u64,
+    pub interval_seconds: i64,
+    pub first_due_ts: i64,
+    pub end_ts: i64
+}
+
+#[derive(Accounts)]
+#[instruction(plan_id: u64)]
+pub struct CreateDcaPlan<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"dca_plan", vault.key().as_ref(), plan_id.to_le_bytes().as_ref()],
+        bump,
+        payer = owner,
+        space = DcaPlan::INIT_SPACE
+    )]
+    pub dca_plan: Box<Account<'info, DcaPlan>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn create_dca_plan_handler(
+    ctx: Context<CreateDcaPlan>,
+    plan_id: u64,
+    params: DcaPlanParams
+) -> Result<()> {
+    validate_spot_order_market(params.market_index)?;
+    require!(
+        params.amount_usd > 0
+            && params.max_base_asset_amount > 0
+            && params.interval_seconds > 0
+            && (params.end_ts == 0 || params.end_ts >= params.first_due_ts),
+        ErrorCode::InvalidDcaPlan
+    );
+
+    let dca_plan = &mut ctx.accounts.dca_plan;
+    dca_plan.vault = ctx.accounts.vault.key();
+    dca_plan.plan_id = plan_id;
+    dca_plan.market_index = params.market_index;
+    dca_plan.side = params.side;
+    dca_plan.amount_usd = params.amount_usd;
+    dca_plan.max_base_asset_amount = params.max_base_asset_amount;
+    dca_plan.limit_price = params.limit_price;
+    dca_plan.interval_seconds = params.interval_seconds;
+    dca_plan.next_due_ts = params.first_due_ts;
+    dca_plan.end_ts = params.end_ts;
+    dca_plan.bump = ctx.bumps.dca_plan;
+
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::place_spot_order, 
+    PlaceSpotOrder as DriftPlaceSpotOrder,
+    MarketType,
+    OrderParams,
+    OrderTriggerCondition,
+    OrderType,
+    PostOnlyParam
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::DRIFT_PROGRAM_ID, 
+    errors::ErrorCode, 
+    price::get_spot_market_price,
+    state::{DcaPlan, Vault},
+    utils::{find_spot_market, roll_period}
+};
+
+#[derive(Accounts)]
+pub struct ExecuteDcaPlan<'info> {
+    #[account(
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"dca_plan", vault.key().as_ref(), dca_plan.plan_id.to_le_bytes().as_ref()],
+        bump = dca_plan.bump,
+        has_one = vault
+    )]
+    pub dca_plan: Box<Account<'info, DcaPlan>>,
+
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+// Remaining accounts are the oracles followed by the spot markets Drift needs for the order's margin check,
+// and must include the plan's spot market and its oracle for pricing
+pub fn execute_dca_plan_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteDcaPlan<'info>>
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    let dca_plan = &mut ctx.accounts.dca_plan;
+
+    require!(current_ts >= dca_plan.next_due_ts, ErrorCode::DcaOrderNotDue);
+    require!(
+        dca_plan.end_ts == 0 || dca_plan.next_due_ts <= dca_plan.end_ts,
+        ErrorCode::DcaPlanEnded
+    );
+
+    // Skip any missed intervals rather than placing a burst of catch-up orders
+    let current_interval_ts = roll_period(dca_plan.next_due_ts, dca_plan.interval_seconds, current_ts)
+        .unwrap_or(dca_plan.next_due_ts);
+    dca_plan.next_due_ts = current_interval_ts + dca_plan.interval_seconds;
+
+    // The order is sized in base units from the plan's USD amount at the current oracle price
+    let spot_market = find_spot_market(ctx.remaining_accounts, dca_plan.market_index)?;
+    let price = get_spot_market_price(&spot_market, ctx.remaining_accounts, current_ts)?;
+    let precision = 10u128.checked_pow(spot_market.decimals).ok_or(ErrorCode::MathOverflow)?;
+    let base_asset_amount = (dca_plan.amount_usd as u128)
+        .checked_mul(precision)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(price as u128)
+        .ok_or(ErrorCode::InvalidOracle)?;
+    let base_asset_amount = u64::try_from(base_asset_amount)
+        .unwrap_or(u64::MAX)
+        .min(dca_plan.max_base_asset_amount);
+    require!(base_asset_amount > 0, ErrorCode::InvalidDcaPlan);
+
+    let order_params = OrderParams {
+        order_type: OrderType::Market,
+        market_type: MarketType::Spot,
+        direction: dca_plan.side.into(),
+        user_order_id: 0,
+        base_asset_amount,
+        price: dca_plan.limit_price,
+        market_index: dca_plan.market_index,
+        reduce_only: false,
+        post_only: PostOnlyParam::None,
+        immediate_or_cancel: false,
+        max_ts: None,
+        trigger_price: None,
+        trigger_condition: OrderTriggerCondition::Above,
+        oracle_price_offset: None,
+        auction_duration: None,
+        auction_start_price: None,
+        auction_end_price: None
+    };
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        DriftPlaceSpotOrder {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+    place_spot_order(cpi_ctx, order_params)?;
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::modify_order, 
+    ModifyOrder as DriftModifyOrder,
+    ModifyOrderParams
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::DRIFT_PROGRAM_ID, 
+    errors::ErrorCode, 
+    state::Vault,
+    utils::validate_not_stop_loss_order
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ModifySpotOrderParams {
+    pub base_asset_amount: Option<u64>,
+    pub price: Option<u64>,
+    pub max_ts: Option<i64>
+}
+
+impl From<ModifySpotOrderParams> for ModifyOrderParams {
+    fn from(params: ModifySpotOrderParams) -> Self {
+        ModifyOrderParams {
+            direction: None,
+            base_asset_amount: params.base_asset_amount,
+            price: params.price,
+            reduce_only: None,
+            post_only: None,
+            immediate_or_cancel: None,
+            max_ts: params.max_ts,
+            trigger_price: None,
+            trigger_condition: None,
+            oracle_price_offset: None,
+            auction_duration: None,
+            auction_start_price: None,
+            auction_end_price: None,
+            policy: None
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct ModifySpotOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+pub fn modify_spot_order_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ModifySpotOrder<'info>>,
+    order_id: u32,
+    params: ModifySpotOrderParams
+) -> Result<()> {
+    {
+        let drift_user = ctx.accounts.drift_user.load()
+            .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+        validate_not_stop_loss_order(&drift_user, order_id)?;
+    }
+
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        DriftModifyOrder {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+    modify_order(cpi_ctx, Some(order_id), params.into())?;
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::place_spot_order, 
+    PlaceSpotOrder as DriftPlaceSpotOrder,
+    MarketType,
+    OrderParams,
+    OrderTriggerCondition,
+    OrderType,
+    PostOnlyParam
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::{DRIFT_PROGRAM_ID, STOP_LOSS_USER_ORDER_ID}, 
+    errors::ErrorCode, 
+    state::{OrderSide, Vault},
+    utils::validate_spot_order_market
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SpotOrderType {
+    Market,
+    Limit
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SpotOrderParams {
+    pub market_index: u16,
+    pub side: OrderSide,
+    pub order_type: SpotOrderType,
+    pub user_order_id: u8,
+    pub base_asset_amount: u64,
+    pub price: u64,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub max_ts: Option<i64>
+}
+
+impl From<SpotOrderParams> for OrderParams {
+    fn from(params: SpotOrderParams) -> Self {
+        OrderParams {
+            order_type: match params.order_type {
+                SpotOrderType::Market => OrderType::Market,
+                SpotOrderType::Limit => OrderType::Limit
+            },
+            market_type: MarketType::Spot,
+            direction: params.side.into(),
+            user_order_id: params.user_order_id,
+            base_asset_amount: params.base_asset_amount,
+            price: params.price,
+            market_index: params.market_index,
+            reduce_only: params.reduce_only,
+            post_only: if params.post_only { PostOnlyParam::MustPostOnly } else { PostOnlyParam::None },
+            immediate_or_cancel: false,
+            max_ts: params.max_ts,
+            trigger_price: None,
+            trigger_condition: OrderTriggerCondition::Above,
+            oracle_price_offset: None,
+            auction_duration: None,
+            auction_start_price: None,
+            auction_end_price: None
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct PlaceSpotOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>
+}
+
+// Remaining accounts are the oracles followed by the spot markets Drift needs for the order's margin check,
+// so orders aren't limited to the SOL and USDC markets
+pub fn place_spot_order_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlaceSpotOrder<'info>>,
+    params: SpotOrderParams
+) -> Result<()> {
+    validate_spot_order_market(params.market_index)?;
+    require!(params.user_order_id != STOP_LOSS_USER_ORDER_ID, ErrorCode::ReservedUserOrderId);
+
+    ctx.accounts.vault.record_activity()?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        DriftPlaceSpotOrder {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+    place_spot_order(cpi_ctx, params.into())?;
+
+    Ok(())
+}
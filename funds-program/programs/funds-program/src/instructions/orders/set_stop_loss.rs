@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    cpi::place_spot_order, 
+    PlaceSpotOrder as DriftPlaceSpotOrder,
+    MarketType,
+    OrderParams,
+    OrderTriggerCondition,
+    OrderType,
+    PositionDirection,
+    PostOnlyParam
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser
+};
+use crate::{
+    constants::{DRIFT_MARKET_INDEX_LST, DRIFT_MARKET_INDEX_SOL, DRIFT_PROGRAM_ID, STOP_LOSS_USER_ORDER_ID}, 
+    errors::ErrorCode, 
+    price::{convert_at_price, get_spot_market_price},
+    state::{StopLoss, Vault},
+    utils::{has_open_order_by_user_id, load_spot_market}
+};
+
+#[derive(Accounts)]
+pub struct SetStopLoss<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and discriminator are checked when it's loaded to price the LST
+    #[account(
+        seeds = [b"spot_market", (DRIFT_MARKET_INDEX_SOL).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and discriminator are checked when it's loaded, only needed in LST mode or if the vault holds the LST
+    #[account(
+        seeds = [b"spot_market", (DRIFT_MARKET_INDEX_LST).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub spot_market_lst: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked against the oracle set on the LST spot market, only needed in LST mode or if the vault holds the LST
+    pub oracle_lst: Option<UncheckedAccount<'info>>
+}
+
+pub fn set_stop_loss_handler(
+    ctx: Context<SetStopLoss>,
+    trigger_price: u64,
+    base_asset_amount: u64
+) -> Result<()> {
+    require!(trigger_price > 0 && base_asset_amount > 0, ErrorCode::InvalidStopLoss);
+
+    // A stop-loss that has already been filled or cancelled on Drift can be replaced
+    {
+        let drift_user = ctx.accounts.drift_user.load()
+            .map_err(|_| ErrorCode::UnableToLoadAccountLoader)?;
+        require!(
+            !has_open_order_by_user_id(&drift_user, STOP_LOSS_USER_ORDER_ID), 
+            ErrorCode::StopLossAlreadySet
+        );
+    }
+
+    ctx.accounts.vault.stop_loss = Some(StopLoss {
+        trigger_price,
+        base_asset_amount
+    });
+    ctx.accounts.vault.record_activity()?;
+
+    let mut oracles = vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info()
+    ];
+    let mut spot_markets = vec![
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info()
+    ];
+    if let (Some(spot_market_lst), Some(oracle_lst)) = (&ctx.accounts.spot_market_lst, &ctx.accounts.oracle_lst) {
+        oracles.push(oracle_lst.to_account_info());
+        spot_markets.push(spot_market_lst.to_account_info());
+    }
+
+    // In LST mode the collateral is the LST, so the stop-loss sells that instead. The SOL trigger price and 
+    // amount are converted at the current LST to SOL price ratio, which only moves with staking rewards
+    let (market_index, order_trigger_price, order_base_asset_amount) = if ctx.accounts.vault.lst_mode {
+        let spot_market_lst = ctx.accounts.spot_market_lst.as_ref()
+            .ok_or(ErrorCode::InvalidSpotMarket)?;
+        let spot_market_lst = load_spot_market(&spot_market_lst.to_account_info())?;
+        let spot_market_sol = load_spot_market(&ctx.accounts.spot_market_sol.to_account_info())?;
+
+        let current_ts = Clock::get()?.unix_timestamp;
+        let lst_price = get_spot_market_price(&spot_market_lst, &oracles, current_ts)?;
+        let sol_price = get_spot_market_price(&spot_market_sol, &oracles, current_ts)?;
+
+        (
+            DRIFT_MARKET_INDEX_LST,
+            convert_at_price(trigger_price, lst_price, sol_price)?,
+            convert_at_price(base_asset_amount, sol_price, lst_price)?
+        )
+    } else {
+        (DRIFT_MARKET_INDEX_SOL, trigger_price, base_asset_amount)
+    };
+
+    let order_params = OrderParams {
+        order_type: OrderType::TriggerMarket,
+        market_type: MarketType::Spot,
+        direction: PositionDirection::Short,
+        user_order_id: STOP_LOSS_USER_ORDER_ID,
+        base_asset_amount: order_base_asset_amount,
+        price: 0,
+        market_index,
+        // Only sells collateral the vault holds, never opens a borrow
+        reduce_only: true,
+        post_only: PostOnlyParam::None,
+        immediate_or_cancel: false,
+        max_ts: None,
+        trigger_price: Some(order_trigger_price),
+        trigger_condition: OrderTriggerCondition::Below,
+        oracle_price_offset: None,
+        auction_duration: None,
+        auction_start_price: None,
+        auction_end_price: None
+    };
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        DriftPlaceSpotOrder {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info()
+        },
+        signer_seeds
+    ).with_remaining_accounts(oracles.into_iter().chain(spot_markets).collect());
+
+    place_spot_order(cpi_ctx, order_params)?;
+
+    Ok(())
+}
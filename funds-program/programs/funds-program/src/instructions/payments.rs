@@ -0,0 +1,38 @@
+mod approve_allowance;
+pub use approve_allowance::*;
+
+mod revoke_allowance;
+pub use revoke_allowance::*;
+
+mod pull_payment;
+pub use pull_payment::*;
+
+mod create_scheduled_payment;
+pub use create_scheduled_payment::*;
+
+mod cancel_scheduled_payment;
+pub use cancel_scheduled_payment::*;
+
+mod execute_scheduled_payment;
+pub use execute_scheduled_payment::*;
+
+mod create_claimable_payment;
+pub use create_claimable_payment::*;
+
+mod claim_payment;
+pub use claim_payment::*;
+
+mod reclaim_payment;
+pub use reclaim_payment::*;
+
+mod set_spend_rules;
+pub use set_spend_rules::*;
+
+mod spend;
+pub use spend::*;
+
+mod set_vault_paused;
+pub use set_vault_paused::*;
+
+mod set_spend_limit;
+pub use set_spend_limit::*;
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Allowance, AllowanceKind, Vault},
+    errors::ErrorCode,
+    utils::get_drift_market_index
+};
+
+#[derive(Accounts)]
+#[instruction(spender: Pubkey, mint: Pubkey)]
+pub struct ApproveAllowance<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"allowance", vault.key().as_ref(), spender.as_ref(), mint.as_ref()],
+        bump,
+        payer = owner,
+        space = Allowance::INIT_SPACE
+    )]
+    pub allowance: Box<Account<'info, Allowance>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn approve_allowance_handler(
+    ctx: Context<ApproveAllowance>,
+    spender: Pubkey,
+    mint: Pubkey,
+    kind: AllowanceKind,
+    amount: u64,
+    expiry_ts: i64,
+    period_seconds: i64
+) -> Result<()> {
+    ctx.accounts.vault.require_owner_threshold(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    require!(period_seconds >= 0, ErrorCode::InvalidAllowancePeriod);
+
+    let market_index = get_drift_market_index(&mint)?;
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    let allowance = &mut ctx.accounts.allowance;
+    allowance.vault = ctx.accounts.vault.key();
+    allowance.spender = spender;
+    allowance.mint = mint;
+    allowance.kind = kind;
+    allowance.market_index = market_index;
+    allowance.amount = amount;
+    allowance.spent_in_period = 0;
+    allowance.period_seconds = period_seconds;
+    allowance.period_start_ts = current_ts;
+    allowance.expiry_ts = expiry_ts;
+    allowance.bump = ctx.bumps.allowance;
+
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
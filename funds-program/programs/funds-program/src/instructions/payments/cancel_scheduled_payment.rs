@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{ScheduledPayment, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct CancelScheduledPayment<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"scheduled_payment", vault.key().as_ref(), scheduled_payment.payment_id.to_le_bytes().as_ref()],
+        bump = scheduled_payment.bump,
+        has_one = vault,
+        close = owner
+    )]
+    pub scheduled_payment: Box<Account<'info, ScheduledPayment>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>
+}
+
+pub fn cancel_scheduled_payment_handler(ctx: Context<CancelScheduledPayment>) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use drift_cpi::{
+    cpi::withdraw, 
+    Withdraw
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::DRIFT_PROGRAM_ID, 
+    errors::ErrorCode, 
+    state::{ClaimablePayment, Vault},
+    utils::get_drift_market_index
+};
+
+#[derive(Accounts)]
+pub struct CreateClaimablePayment<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"claimable_payment", vault.key().as_ref(), claim_authority.key().as_ref()],
+        bump,
+        payer = owner,
+        space = ClaimablePayment::INIT_SPACE
+    )]
+    pub claimable_payment: Box<Account<'info, ClaimablePayment>>,
+
+    #[account(
+        init,
+        seeds = [b"escrow", claimable_payment.key().as_ref()],
+        bump,
+        payer = owner,
+        token::mint = mint,
+        token::authority = claimable_payment
+    )]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Only the address is stored, it must sign to claim the payment
+    pub claim_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", get_drift_market_index(&mint.key())?.to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        token::mint = mint,
+        bump,
+    )]
+    pub spot_market_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub drift_signer: UncheckedAccount<'info>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_claimable_payment_handler(
+    ctx: Context<CreateClaimablePayment>,
+    amount: u64,
+    expiry_ts: i64,
+    reduce_only: bool
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    require!(expiry_ts > current_ts, ErrorCode::InvalidPaymentExpiry);
+
+    ctx.accounts.vault.record_activity()?;
+
+    let claimable_payment = &mut ctx.accounts.claimable_payment;
+    claimable_payment.vault = ctx.accounts.vault.key();
+    claimable_payment.claim_authority = ctx.accounts.claim_authority.key();
+    claimable_payment.creator = ctx.accounts.owner.key();
+    claimable_payment.mint = ctx.accounts.mint.key();
+    claimable_payment.amount = amount;
+    claimable_payment.expiry_ts = expiry_ts;
+    claimable_payment.bump = ctx.bumps.claimable_payment;
+
+    let market_index = get_drift_market_index(&ctx.accounts.mint.key())?;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Withdraw from Drift straight into the escrow
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Withdraw {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            drift_signer: ctx.accounts.drift_signer.to_account_info(),
+            user_token_account: ctx.accounts.escrow.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    ).with_remaining_accounts(vec![
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info()
+    ]);
+
+    withdraw(cpi_ctx, market_index, amount, reduce_only)?;
+
+    Ok(())
+}
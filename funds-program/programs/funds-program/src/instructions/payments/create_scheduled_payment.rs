@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    state::{ScheduledPayment, Vault},
+    errors::ErrorCode,
+    utils::get_drift_market_index
+};
+
+#[derive(Accounts)]
+#[instruction(payment_id: u64)]
+pub struct CreateScheduledPayment<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"scheduled_payment", vault.key().as_ref(), payment_id.to_le_bytes().as_ref()],
+        bump,
+        payer = owner,
+        space = ScheduledPayment::INIT_SPACE
+    )]
+    pub scheduled_payment: Box<Account<'info, ScheduledPayment>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Only the address is stored, payments must go to a token account it owns
+    pub recipient: UncheckedAccount<'info>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn create_scheduled_payment_handler(
+    ctx: Context<CreateScheduledPayment>,
+    payment_id: u64,
+    amount: u64,
+    crank_tip: u64,
+    interval_seconds: i64,
+    first_due_ts: i64,
+    end_ts: i64
+) -> Result<()> {
+    require!(
+        amount > 0 && interval_seconds > 0 && (end_ts == 0 || end_ts >= first_due_ts),
+        ErrorCode::InvalidPaymentSchedule
+    );
+
+    ctx.accounts.vault.validate_payee(&ctx.accounts.recipient.key())?;
+
+    let market_index = get_drift_market_index(&ctx.accounts.mint.key())?;
+
+    let scheduled_payment = &mut ctx.accounts.scheduled_payment;
+    scheduled_payment.vault = ctx.accounts.vault.key();
+    scheduled_payment.payment_id = payment_id;
+    scheduled_payment.recipient = ctx.accounts.recipient.key();
+    scheduled_payment.mint = ctx.accounts.mint.key();
+    scheduled_payment.market_index = market_index;
+    scheduled_payment.amount = amount;
+    scheduled_payment.crank_tip = crank_tip;
+    scheduled_payment.interval_seconds = interval_seconds;
+    scheduled_payment.next_due_ts = first_due_ts;
+    scheduled_payment.end_ts = end_ts;
+    scheduled_payment.bump = ctx.bumps.scheduled_payment;
+
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::{
+    errors::ErrorCode, 
+    state::{ClaimablePayment, Vault}
+};
+
+#[derive(Accounts)]
+pub struct ReclaimPayment<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"claimable_payment", vault.key().as_ref(), claimable_payment.claim_authority.as_ref()],
+        bump = claimable_payment.bump,
+        has_one = vault,
+        has_one = creator,
+        has_one = mint,
+        close = creator
+    )]
+    pub claimable_payment: Box<Account<'info, ClaimablePayment>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", claimable_payment.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Receives the rent, address is checked against the payment
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner
+    )]
+    pub owner_spl: Box<Account<'info, TokenAccount>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>
+}
+
+pub fn reclaim_payment_handler(ctx: Context<ReclaimPayment>) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+
+    let claimable_payment = &ctx.accounts.claimable_payment;
+
+    require!(
+        Clock::get()?.unix_timestamp >= claimable_payment.expiry_ts,
+        ErrorCode::PaymentNotExpired
+    );
+
+    let vault = ctx.accounts.vault.key();
+    let seeds = &[
+        b"claimable_payment",
+        vault.as_ref(),
+        claimable_payment.claim_authority.as_ref(),
+        &[claimable_payment.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Return escrow to owner
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(), 
+            token::Transfer { 
+                from: ctx.accounts.escrow.to_account_info(), 
+                to: ctx.accounts.owner_spl.to_account_info(), 
+                authority: ctx.accounts.claimable_payment.to_account_info()
+            }, 
+            signer_seeds
+        ),
+        ctx.accounts.escrow.amount
+    )?;
+
+    // Close escrow
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.claimable_payment.to_account_info(),
+        },
+        signer_seeds
+    );
+    token::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
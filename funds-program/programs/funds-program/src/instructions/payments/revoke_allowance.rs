@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Allowance, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct RevokeAllowance<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"allowance", vault.key().as_ref(), allowance.spender.as_ref(), allowance.mint.as_ref()],
+        bump = allowance.bump,
+        has_one = vault,
+        close = owner
+    )]
+    pub allowance: Box<Account<'info, Allowance>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>
+}
+
+pub fn revoke_allowance_handler(ctx: Context<RevokeAllowance>) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+    Ok(())
+}
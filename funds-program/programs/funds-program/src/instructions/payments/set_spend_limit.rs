@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct SetSpendLimit<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_spend_limit_handler(
+    ctx: Context<SetSpendLimit>,
+    limit_usd: u64,
+    period_seconds: i64
+) -> Result<()> {
+    ctx.accounts.vault.require_owner_threshold(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    let vault = &mut ctx.accounts.vault;
+
+    vault.set_spend_limit(limit_usd, period_seconds, current_ts)?;
+    vault.record_activity()?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{CategoryCap, Vault},
+    errors::ErrorCode,
+    constants::{MAX_ALLOWED_PAYEES, MAX_BLOCKED_MCCS, MAX_CATEGORY_CAPS}
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CategoryCapParams {
+    pub mcc: u16,
+    pub monthly_cap_usd: u64
+}
+
+#[derive(Accounts)]
+pub struct SetSpendRules<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_spend_rules_handler(
+    ctx: Context<SetSpendRules>,
+    blocked_mccs: Vec<u16>,
+    allowed_payees: Vec<Pubkey>,
+    category_caps: Vec<CategoryCapParams>
+) -> Result<()> {
+    require!(
+        blocked_mccs.len() <= MAX_BLOCKED_MCCS 
+            && allowed_payees.len() <= MAX_ALLOWED_PAYEES 
+            && category_caps.len() <= MAX_CATEGORY_CAPS,
+        ErrorCode::TooManySpendRules
+    );
+
+    let vault = &mut ctx.accounts.vault;
+
+    // Spending already counted this period carries over for categories that keep a cap
+    let category_caps = category_caps.iter()
+        .map(|params| CategoryCap {
+            mcc: params.mcc,
+            monthly_cap_usd: params.monthly_cap_usd,
+            spent_in_period_usd: vault.category_caps.iter()
+                .find(|cap| cap.mcc == params.mcc)
+                .map_or(0, |cap| cap.spent_in_period_usd)
+        })
+        .collect();
+
+    vault.blocked_mccs = blocked_mccs;
+    vault.allowed_payees = allowed_payees;
+    vault.category_caps = category_caps;
+    vault.record_activity()?;
+
+    Ok(())
+}
@@ -0,0 +1,309 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::ID as TOKEN_2022_PROGRAM_ID,
+    token_interface::{
+        self, 
+        harvest_withheld_tokens_to_mint, 
+        HarvestWithheldTokensToMint, 
+        Mint, 
+        TokenAccount, 
+        TokenInterface
+    }
+};
+use drift_cpi::{
+    cpi::withdraw, 
+    Withdraw
+};
+use drift_accounts::{
+    State as DriftState,
+    User as DriftUser,
+    UserStats as DriftUserStats
+};
+use crate::{
+    constants::DRIFT_PROGRAM_ID, 
+    errors::ErrorCode, 
+    price::get_spot_usd_value,
+    state::{Allowance, AllowanceKind, Card, SpendRecord, Vault},
+    utils::{mint_has_transfer_fee, post_price_update, PythPriceUpdate}
+};
+
+#[derive(Accounts)]
+#[instruction(spend_id: u64)]
+pub struct Spend<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"spend_record", vault.key().as_ref(), spend_id.to_le_bytes().as_ref()],
+        bump,
+        payer = spender,
+        space = SpendRecord::INIT_SPACE
+    )]
+    pub spend_record: Box<Account<'info, SpendRecord>>,
+
+    #[account(
+        mut,
+        seeds = [b"card", vault.key().as_ref(), card.card_id.to_le_bytes().as_ref()],
+        bump = card.bump,
+        has_one = vault
+    )]
+    pub card: Box<Account<'info, Card>>,
+
+    #[account(
+        mut,
+        seeds = [b"allowance", vault.key().as_ref(), spender.key().as_ref(), mint.key().as_ref()],
+        bump = allowance.bump,
+        has_one = vault,
+        has_one = spender,
+        has_one = mint,
+        constraint = allowance.kind == AllowanceKind::Card @ ErrorCode::InvalidAllowanceKind
+    )]
+    pub allowance: Box<Account<'info, Allowance>>,
+
+    #[account(
+        init,
+        seeds = [vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+        payer = spender,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault_spl: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub spender: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = spender,
+        token::token_program = token_program
+    )]
+    pub spender_spl: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"drift_state"],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_state: Box<Account<'info, DriftState>>,
+
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), (0u16).to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user: AccountLoader<'info, DriftUser>,
+    
+    #[account(
+        mut,
+        seeds = [b"user_stats", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump
+    )]
+    pub drift_user_stats: AccountLoader<'info, DriftUserStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"spot_market_vault", allowance.market_index.to_le_bytes().as_ref()],
+        seeds::program = drift_program.key(),
+        token::mint = mint,
+        token::token_program = token_program,
+        bump,
+    )]
+    pub spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub drift_signer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Account is safe once the address is correct
+    #[account(
+        constraint = drift_program.key() == DRIFT_PROGRAM_ID @ ErrorCode::InvalidDriftProgram
+    )]
+    pub drift_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub const_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    pub additional_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_sol: UncheckedAccount<'info>,
+
+    /// CHECK: This account is passed through to the Drift CPI, which performs the security checks
+    #[account(mut)]
+    pub spot_market_usdc: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the oracle set on the spent asset's Drift spot market
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub pyth_solana_receiver: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    pub guardian_set: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Only needed with a price update, passed through to the Drift CPI which performs the security checks
+    #[account(mut)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Card spend made by the card manager through its allowance, checked against the vault's merchant category rules
+pub fn spend_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Spend<'info>>, 
+    spend_id: u64,
+    amount: u64,
+    mcc: u16,
+    merchant_id: [u8; 32],
+    price_update: Option<PythPriceUpdate>
+) -> Result<()> {
+    let current_ts = Clock::get()?.unix_timestamp;
+    ctx.accounts.allowance.consume(amount, current_ts)?;
+
+    post_price_update(
+        price_update,
+        &ctx.accounts.spender.to_account_info(),
+        &ctx.accounts.drift_program.to_account_info(),
+        ctx.accounts.pyth_solana_receiver.as_ref(),
+        ctx.accounts.guardian_set.as_ref(),
+        ctx.accounts.price_feed.as_ref()
+    )?;
+
+    // Spot markets other than SOL and USDC, such as PYUSD, are passed as remaining accounts
+    let market_accounts: Vec<AccountInfo<'info>> = [
+        ctx.accounts.oracle.to_account_info(),
+        ctx.accounts.spot_market_sol.to_account_info(),
+        ctx.accounts.spot_market_usdc.to_account_info()
+    ].into_iter().chain(ctx.remaining_accounts.iter().cloned()).collect();
+
+    // Category caps are in dollars so they apply the same way to every asset
+    let amount_usd = get_spot_usd_value(
+        ctx.accounts.allowance.market_index,
+        amount,
+        &market_accounts,
+        current_ts
+    )?;
+
+    ctx.accounts.card.consume(amount_usd, current_ts)?;
+    ctx.accounts.vault.record_category_spend(mcc, amount_usd, current_ts)?;
+    ctx.accounts.vault.record_outflow(amount_usd, current_ts)?;
+
+    let spend_record = &mut ctx.accounts.spend_record;
+    spend_record.vault = ctx.accounts.vault.key();
+    spend_record.spend_id = spend_id;
+    spend_record.spender = ctx.accounts.spender.key();
+    spend_record.mint = ctx.accounts.mint.key();
+    spend_record.amount = amount;
+    spend_record.amount_usd = amount_usd;
+    spend_record.card_id = ctx.accounts.card.card_id;
+    spend_record.mcc = mcc;
+    spend_record.merchant_id = merchant_id;
+    spend_record.spend_ts = current_ts;
+    spend_record.bump = ctx.bumps.spend_record;
+
+    let vault_bump = ctx.accounts.vault.bump;
+    let owner_seed = ctx.accounts.vault.owner_seed;
+    let vault_id_seed = ctx.accounts.vault.vault_id_seed();
+    let seeds = &[
+        b"vault",
+        owner_seed.as_ref(),
+        vault_id_seed.as_ref(),
+        &[vault_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Build Drift Withdraw CPI
+    let mut cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        Withdraw {
+            state: ctx.accounts.drift_state.to_account_info(),
+            user: ctx.accounts.drift_user.to_account_info(),
+            user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+            spot_market_vault: ctx.accounts.spot_market_vault.to_account_info(),
+            drift_signer: ctx.accounts.drift_signer.to_account_info(),
+            user_token_account: ctx.accounts.vault_spl.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer_seeds
+    );
+
+    // Add remaining accounts, Drift expects the mint last for Token-2022 markets
+    cpi_ctx.remaining_accounts = [
+        ctx.accounts.const_account.to_account_info(),
+        ctx.accounts.additional_account.to_account_info()
+    ].into_iter().chain(market_accounts).collect();
+    if ctx.accounts.token_program.key() == TOKEN_2022_PROGRAM_ID {
+        cpi_ctx.remaining_accounts.push(ctx.accounts.mint.to_account_info());
+    }
+
+    // Card spends can only use deposits, never open a borrow on the owner's behalf
+    withdraw(cpi_ctx, ctx.accounts.allowance.market_index, amount, true)?;
+
+    // Transfer fees can reduce what arrives from Drift, so forward the received balance
+
+    ctx.accounts.vault_spl.reload()?;
+    let received_amount = ctx.accounts.vault_spl.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(), 
+            token_interface::TransferChecked { 
+                from: ctx.accounts.vault_spl.to_account_info(), 
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.spender_spl.to_account_info(), 
+                authority: ctx.accounts.vault.to_account_info()
+            }, 
+            signer_seeds
+        ),
+        received_amount,
+        ctx.accounts.mint.decimals
+    )?;
+
+    // Harvest withheld fees so the vault token account can be closed
+
+    if mint_has_transfer_fee(&ctx.accounts.mint.to_account_info())? {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info()
+                }
+            ),
+            vec![ctx.accounts.vault_spl.to_account_info()]
+        )?;
+    }
+
+    // Close vault token account, returning rent to the spender
+
+    let cpi_ctx_close = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.vault_spl.to_account_info(),
+            destination: ctx.accounts.spender.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds
+    );
+    token_interface::close_account(cpi_ctx_close)?;
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+mod set_guardians;
+pub use set_guardians::*;
+
+mod initiate_recovery;
+pub use initiate_recovery::*;
+
+mod approve_recovery;
+pub use approve_recovery::*;
+
+mod cancel_recovery;
+pub use cancel_recovery::*;
+
+mod execute_recovery;
+pub use execute_recovery::*;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{RecoveryRequest, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    #[account(
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.guardians.contains(&guardian.key()) @ ErrorCode::NotAGuardian
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"recovery", vault.key().as_ref()],
+        bump = recovery_request.bump,
+        has_one = vault
+    )]
+    pub recovery_request: Box<Account<'info, RecoveryRequest>>,
+
+    pub guardian: Signer<'info>
+}
+
+pub fn approve_recovery_handler(ctx: Context<ApproveRecovery>) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+    let recovery_request = &mut ctx.accounts.recovery_request;
+
+    require!(
+        !recovery_request.approvals.contains(&guardian),
+        ErrorCode::RecoveryAlreadyApproved
+    );
+
+    recovery_request.approvals.push(guardian);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{RecoveryRequest, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"recovery", vault.key().as_ref()],
+        bump = recovery_request.bump,
+        has_one = vault,
+        has_one = initiator,
+        close = initiator
+    )]
+    pub recovery_request: Box<Account<'info, RecoveryRequest>>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Receives the recovery request rent, address is checked against the request
+    #[account(mut)]
+    pub initiator: UncheckedAccount<'info>
+}
+
+pub fn cancel_recovery_handler(ctx: Context<CancelRecovery>) -> Result<()> {
+    ctx.accounts.vault.record_activity()?;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{RecoveryRequest, Vault},
+    errors::ErrorCode,
+    constants::RECOVERY_CANCELLATION_WINDOW_SECONDS
+};
+
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"recovery", vault.key().as_ref()],
+        bump = recovery_request.bump,
+        has_one = vault,
+        has_one = new_owner,
+        has_one = initiator,
+        close = initiator
+    )]
+    pub recovery_request: Box<Account<'info, RecoveryRequest>>,
+
+    pub new_owner: Signer<'info>,
+
+    /// CHECK: Receives the recovery request rent, address is checked against the request
+    #[account(mut)]
+    pub initiator: UncheckedAccount<'info>
+}
+
+pub fn execute_recovery_handler(ctx: Context<ExecuteRecovery>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let recovery_request = &ctx.accounts.recovery_request;
+
+    require!(
+        vault.guardian_threshold > 0
+            && recovery_request.approval_count(vault) >= vault.guardian_threshold as usize,
+        ErrorCode::RecoveryThresholdNotMet
+    );
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    require!(
+        current_ts >= recovery_request.initiated_ts + RECOVERY_CANCELLATION_WINDOW_SECONDS,
+        ErrorCode::RecoveryWindowActive
+    );
+
+    ctx.accounts.vault.owner = ctx.accounts.new_owner.key();
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{RecoveryRequest, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct InitiateRecovery<'info> {
+    #[account(
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.guardians.contains(&guardian.key()) @ ErrorCode::NotAGuardian,
+        constraint = new_owner != vault.owner @ ErrorCode::InvalidNewOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"recovery", vault.key().as_ref()],
+        bump,
+        payer = guardian,
+        space = RecoveryRequest::INIT_SPACE
+    )]
+    pub recovery_request: Box<Account<'info, RecoveryRequest>>,
+
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn initiate_recovery_handler(
+    ctx: Context<InitiateRecovery>,
+    new_owner: Pubkey
+) -> Result<()> {
+    let recovery_request = &mut ctx.accounts.recovery_request;
+    recovery_request.vault = ctx.accounts.vault.key();
+    recovery_request.new_owner = new_owner;
+    recovery_request.initiator = ctx.accounts.guardian.key();
+    recovery_request.approvals = vec![ctx.accounts.guardian.key()];
+    recovery_request.initiated_ts = Clock::get()?.unix_timestamp;
+    recovery_request.bump = ctx.bumps.recovery_request;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode,
+    constants::MAX_GUARDIANS
+};
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner,
+        constraint = !vault.is_child() @ ErrorCode::ManagedByParentVault
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Only checked for existing data, guardians can't change while a recovery is pending
+    #[account(
+        seeds = [b"recovery", vault.key().as_ref()],
+        bump
+    )]
+    pub recovery_request: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_guardians_handler(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    threshold: u8
+) -> Result<()> {
+    ctx.accounts.vault.require_owner_threshold(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    require!(ctx.accounts.recovery_request.data_is_empty(), ErrorCode::RecoveryPending);
+    require!(guardians.len() <= MAX_GUARDIANS, ErrorCode::TooManyGuardians);
+
+    // An empty guardian list with a zero threshold disables social recovery
+    require!(
+        (guardians.is_empty() && threshold == 0)
+            || (threshold > 0 && (threshold as usize) <= guardians.len()),
+        ErrorCode::InvalidGuardianThreshold
+    );
+
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(
+            !ctx.accounts.vault.is_owner(guardian) && !guardians[..i].contains(guardian),
+            ErrorCode::InvalidGuardian
+        );
+    }
+
+    ctx.accounts.vault.guardians = guardians;
+    ctx.accounts.vault.guardian_threshold = threshold;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
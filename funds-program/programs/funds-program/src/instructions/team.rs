@@ -0,0 +1,20 @@
+mod set_team_mode;
+pub use set_team_mode::*;
+
+mod add_team_member;
+pub use add_team_member::*;
+
+mod remove_team_member;
+pub use remove_team_member::*;
+
+mod request_team_spend;
+pub use request_team_spend::*;
+
+mod approve_team_spend;
+pub use approve_team_spend::*;
+
+mod reject_team_spend;
+pub use reject_team_spend::*;
+
+mod team_spend;
+pub use team_spend::*;
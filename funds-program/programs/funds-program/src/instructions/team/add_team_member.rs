@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{TeamMember, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AddTeamMember<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        seeds = [b"team_member", vault.key().as_ref(), member.as_ref()],
+        bump,
+        payer = owner,
+        space = TeamMember::INIT_SPACE
+    )]
+    pub team_member: Box<Account<'info, TeamMember>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn add_team_member_handler(
+    ctx: Context<AddTeamMember>,
+    member: Pubkey,
+    is_admin: bool,
+    limit_usd: u64,
+    period_seconds: i64
+) -> Result<()> {
+    require!(period_seconds >= 0, ErrorCode::InvalidTeamMemberLimit);
+    require!(!ctx.accounts.vault.is_owner(&member), ErrorCode::InvalidTeamMember);
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    let team_member = &mut ctx.accounts.team_member;
+    team_member.vault = ctx.accounts.vault.key();
+    team_member.member = member;
+    team_member.is_admin = is_admin;
+    team_member.limit_usd = limit_usd;
+    team_member.spent_in_period_usd = 0;
+    team_member.period_seconds = period_seconds;
+    team_member.period_start_ts = current_ts;
+    team_member.added_ts = current_ts;
+    team_member.bump = ctx.bumps.team_member;
+
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
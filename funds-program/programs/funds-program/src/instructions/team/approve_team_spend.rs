@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{PendingApproval, TeamMember, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct ApproveTeamSpend<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.team_mode @ ErrorCode::TeamModeDisabled
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_approval", vault.key().as_ref(), pending_approval.request_id.to_le_bytes().as_ref()],
+        bump = pending_approval.bump,
+        has_one = vault
+    )]
+    pub pending_approval: Box<Account<'info, PendingApproval>>,
+
+    pub approver: Signer<'info>,
+
+    // Only needed when the approver is a team admin rather than the owner
+    #[account(
+        seeds = [b"team_member", vault.key().as_ref(), approver.key().as_ref()],
+        bump = approver_member.bump,
+        has_one = vault
+    )]
+    pub approver_member: Option<Account<'info, TeamMember>>
+}
+
+pub fn approve_team_spend_handler(ctx: Context<ApproveTeamSpend>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    require!(
+        ctx.accounts.vault.can_approve_team_spend(&approver, ctx.accounts.approver_member.as_deref()),
+        ErrorCode::NotATeamAdmin
+    );
+    require!(approver != ctx.accounts.pending_approval.member, ErrorCode::NotATeamAdmin);
+    require!(ctx.accounts.pending_approval.approver.is_none(), ErrorCode::TeamSpendAlreadyApproved);
+
+    // The requesting member executes the spend with team_spend once it's approved
+    ctx.accounts.pending_approval.approver = Some(approver);
+
+    if ctx.accounts.vault.is_owner(&approver) {
+        ctx.accounts.vault.record_activity()?;
+    }
+
+    Ok(())
+}
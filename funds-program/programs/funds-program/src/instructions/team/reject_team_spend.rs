@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{PendingApproval, TeamMember, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct RejectTeamSpend<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_approval", vault.key().as_ref(), pending_approval.request_id.to_le_bytes().as_ref()],
+        bump = pending_approval.bump,
+        has_one = vault,
+        has_one = member,
+        close = member
+    )]
+    pub pending_approval: Box<Account<'info, PendingApproval>>,
+
+    /// CHECK: Receives the rent back, checked against the pending approval
+    #[account(mut)]
+    pub member: UncheckedAccount<'info>,
+
+    pub approver: Signer<'info>,
+
+    // Only needed when the approver is a team admin rather than the owner
+    #[account(
+        seeds = [b"team_member", vault.key().as_ref(), approver.key().as_ref()],
+        bump = approver_member.bump,
+        has_one = vault
+    )]
+    pub approver_member: Option<Account<'info, TeamMember>>
+}
+
+pub fn reject_team_spend_handler(ctx: Context<RejectTeamSpend>) -> Result<()> {
+    // The requesting member can also withdraw their own request
+    let approver = ctx.accounts.approver.key();
+    require!(
+        approver == ctx.accounts.member.key() 
+            || ctx.accounts.vault.can_approve_team_spend(&approver, ctx.accounts.approver_member.as_deref()),
+        ErrorCode::NotATeamAdmin
+    );
+
+    if ctx.accounts.vault.is_owner(&approver) {
+        ctx.accounts.vault.record_activity()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{TeamMember, Vault},
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct RemoveTeamMember<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [b"team_member", vault.key().as_ref(), team_member.member.as_ref()],
+        bump = team_member.bump,
+        has_one = vault,
+        close = owner
+    )]
+    pub team_member: Box<Account<'info, TeamMember>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>
+}
+
+pub fn remove_team_member_handler(ctx: Context<RemoveTeamMember>) -> Result<()> {
+    // The member's pending approvals can't be executed without the member account, and predate it if re-added
+    ctx.accounts.vault.record_activity()?;
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    state::{PendingApproval, TeamMember, Vault},
+    errors::ErrorCode,
+    utils::get_drift_market_index
+};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct RequestTeamSpend<'info> {
+    #[account(
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.team_mode @ ErrorCode::TeamModeDisabled
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [b"team_member", vault.key().as_ref(), member.key().as_ref()],
+        bump = team_member.bump,
+        has_one = vault,
+        has_one = member
+    )]
+    pub team_member: Box<Account<'info, TeamMember>>,
+
+    #[account(
+        init,
+        seeds = [b"pending_approval", vault.key().as_ref(), request_id.to_le_bytes().as_ref()],
+        bump,
+        payer = member,
+        space = PendingApproval::INIT_SPACE
+    )]
+    pub pending_approval: Box<Account<'info, PendingApproval>>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>
+}
+
+pub fn request_team_spend_handler(
+    ctx: Context<RequestTeamSpend>,
+    request_id: u64,
+    amount: u64
+) -> Result<()> {
+    get_drift_market_index(&ctx.accounts.mint.key())?;
+
+    let pending_approval = &mut ctx.accounts.pending_approval;
+    pending_approval.vault = ctx.accounts.vault.key();
+    pending_approval.request_id = request_id;
+    pending_approval.member = ctx.accounts.member.key();
+    pending_approval.mint = ctx.accounts.mint.key();
+    pending_approval.amount = amount;
+    pending_approval.approver = None;
+    pending_approval.created_ts = Clock::get()?.unix_timestamp;
+    pending_approval.bump = ctx.bumps.pending_approval;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Vault,
+    errors::ErrorCode
+};
+
+#[derive(Accounts)]
+pub struct SetTeamMode<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.owner_seed.as_ref(), vault.vault_id_seed().as_ref()],
+        bump = vault.bump,
+        constraint = vault.is_owner(&owner.key()) @ ErrorCode::NotAVaultOwner
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_team_mode_handler(
+    ctx: Context<SetTeamMode>,
+    enabled: bool,
+    approval_threshold_usd: u64
+) -> Result<()> {
+    // Member accounts are kept while team mode is off, so it can be switched back on without re-adding everyone
+    ctx.accounts.vault.team_mode = enabled;
+    ctx.accounts.vault.team_approval_threshold_usd = approval_threshold_usd;
+    ctx.accounts.vault.record_activity()?;
+
+    Ok(())
+}
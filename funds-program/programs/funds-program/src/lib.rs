@@ -3,8 +3,13 @@ use anchor_lang::prelude::*;
 mod state;
 mod errors;
 mod constants;
+mod utils;
+mod stake_pool;
+mod price;
 mod instructions;
 use instructions::*;
+use state::AllowanceKind;
+use utils::PythPriceUpdate;
 
 declare_id!("6JjHXLheGSNvvexgzMthEcgjkcirDrGduc3HAKB2P1v2");
 
@@ -30,22 +35,62 @@ pub mod funds_program {
         init_user_handler(ctx)
     }
 
+    pub fn init_owner_registry(ctx: Context<InitOwnerRegistry>) -> Result<()> {
+        init_owner_registry_handler(ctx)
+    }
+
+    pub fn init_vault(ctx: Context<InitVault>, vault_id: u16) -> Result<()> {
+        init_vault_handler(ctx, vault_id)
+    }
+
+    pub fn migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+        migrate_vault_handler(ctx)
+    }
+
+    pub fn init_joint_vault(ctx: Context<InitJointVault>, co_owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        init_joint_vault_handler(ctx, co_owners, threshold)
+    }
+
     pub fn close_user(ctx: Context<CloseUser>) -> Result<()> {
         close_user_handler(ctx)
     }
 
-    pub fn init_drift_account(ctx: Context<InitDriftAccount>) -> Result<()> {
-        init_drift_account_handler(ctx)
+    pub fn init_drift_account(ctx: Context<InitDriftAccount>, name: [u8; 32]) -> Result<()> {
+        init_drift_account_handler(ctx, name)
     }
 
     pub fn close_drift_account(ctx: Context<CloseDriftAccount>) -> Result<()> {
         close_drift_account_handler(ctx)
     }
 
+    pub fn set_drift_delegate(ctx: Context<SetDriftDelegate>, delegate: Pubkey) -> Result<()> {
+        set_drift_delegate_handler(ctx, delegate)
+    }
+
+    pub fn clear_drift_delegate(ctx: Context<ClearDriftDelegate>) -> Result<()> {
+        clear_drift_delegate_handler(ctx)
+    }
+
+    pub fn set_custom_margin_ratio(ctx: Context<SetCustomMarginRatio>, margin_ratio: u32) -> Result<()> {
+        set_custom_margin_ratio_handler(ctx, margin_ratio)
+    }
+
+    pub fn set_margin_trading_enabled(ctx: Context<SetMarginTradingEnabled>, margin_trading_enabled: bool) -> Result<()> {
+        set_margin_trading_enabled_handler(ctx, margin_trading_enabled)
+    }
+
+    pub fn set_lst_mode(ctx: Context<SetLstMode>, enabled: bool) -> Result<()> {
+        set_lst_mode_handler(ctx, enabled)
+    }
+
+    pub fn set_co_owners(ctx: Context<SetCoOwners>, co_owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        set_co_owners_handler(ctx, co_owners, threshold)
+    }
+
     // Balance
 
-    pub fn withdraw_lamports(ctx: Context<WithdrawLamports>, amount: u64, reduce_only: bool) -> Result<()> {
-        withdraw_lamports_handler(ctx, amount, reduce_only)
+    pub fn withdraw_lamports(ctx: Context<WithdrawLamports>, amount: u64, reduce_only: bool, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        withdraw_lamports_handler(ctx, amount, reduce_only, price_update)
     }
 
     pub fn deposit_lamports(ctx: Context<DepositLamports>, amount: u64, reduce_only: bool) -> Result<()> {
@@ -56,7 +101,298 @@ pub mod funds_program {
         deposit_usdc_handler(ctx, amount_micro_cents, reduce_only)
     }
 
-    pub fn withdraw_usdc(ctx: Context<WithdrawUsdc>, amount_micro_cents: u64, reduce_only: bool) -> Result<()> {
-        withdraw_usdc_handler(ctx, amount_micro_cents, reduce_only)
+    pub fn withdraw_usdc(ctx: Context<WithdrawUsdc>, amount_micro_cents: u64, reduce_only: bool, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        withdraw_usdc_handler(ctx, amount_micro_cents, reduce_only, price_update)
+    }
+
+    pub fn deposit_wsol(ctx: Context<DepositWsol>, amount: u64, reduce_only: bool) -> Result<()> {
+        deposit_wsol_handler(ctx, amount, reduce_only)
+    }
+
+    pub fn withdraw_wsol(ctx: Context<WithdrawWsol>, amount: u64, reduce_only: bool, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        withdraw_wsol_handler(ctx, amount, reduce_only, price_update)
+    }
+
+    pub fn deposit_spl(ctx: Context<DepositSpl>, amount: u64, reduce_only: bool) -> Result<()> {
+        deposit_spl_handler(ctx, amount, reduce_only)
+    }
+
+    pub fn withdraw_spl(ctx: Context<WithdrawSpl>, amount: u64, reduce_only: bool, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        withdraw_spl_handler(ctx, amount, reduce_only, price_update)
+    }
+
+    pub fn withdraw_lamports_lst(ctx: Context<WithdrawLamportsLst>, lst_amount: u64, reduce_only: bool, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        withdraw_lamports_lst_handler(ctx, lst_amount, reduce_only, price_update)
+    }
+
+    pub fn batch_deposit<'info>(ctx: Context<'_, '_, 'info, 'info, BatchDeposit<'info>>, legs: Vec<BalanceLeg>) -> Result<()> {
+        batch_deposit_handler(ctx, legs)
+    }
+
+    pub fn batch_withdraw<'info>(ctx: Context<'_, '_, 'info, 'info, BatchWithdraw<'info>>, legs: Vec<BalanceLeg>) -> Result<()> {
+        batch_withdraw_handler(ctx, legs)
+    }
+
+    pub fn get_collateral_value(ctx: Context<GetCollateralValue>) -> Result<u64> {
+        get_collateral_value_handler(ctx)
+    }
+
+    // Recovery
+
+    pub fn set_guardians(ctx: Context<SetGuardians>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        set_guardians_handler(ctx, guardians, threshold)
+    }
+
+    pub fn initiate_recovery(ctx: Context<InitiateRecovery>, new_owner: Pubkey) -> Result<()> {
+        initiate_recovery_handler(ctx, new_owner)
+    }
+
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        approve_recovery_handler(ctx)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        cancel_recovery_handler(ctx)
+    }
+
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+        execute_recovery_handler(ctx)
+    }
+
+    // Inheritance
+
+    pub fn set_beneficiary(ctx: Context<SetBeneficiary>, beneficiary: Option<Pubkey>, inactivity_period_seconds: i64) -> Result<()> {
+        set_beneficiary_handler(ctx, beneficiary, inactivity_period_seconds)
+    }
+
+    pub fn claim_inheritance(ctx: Context<ClaimInheritance>) -> Result<()> {
+        claim_inheritance_handler(ctx)
+    }
+
+    // Payments
+
+    pub fn approve_allowance(
+        ctx: Context<ApproveAllowance>,
+        spender: Pubkey,
+        mint: Pubkey,
+        kind: AllowanceKind,
+        amount: u64,
+        expiry_ts: i64,
+        period_seconds: i64
+    ) -> Result<()> {
+        approve_allowance_handler(ctx, spender, mint, kind, amount, expiry_ts, period_seconds)
+    }
+
+    pub fn revoke_allowance(ctx: Context<RevokeAllowance>) -> Result<()> {
+        revoke_allowance_handler(ctx)
+    }
+
+    pub fn pull_payment<'info>(ctx: Context<'_, '_, 'info, 'info, PullPayment<'info>>, amount: u64, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        pull_payment_handler(ctx, amount, price_update)
+    }
+
+    pub fn create_scheduled_payment(
+        ctx: Context<CreateScheduledPayment>,
+        payment_id: u64,
+        amount: u64,
+        crank_tip: u64,
+        interval_seconds: i64,
+        first_due_ts: i64,
+        end_ts: i64
+    ) -> Result<()> {
+        create_scheduled_payment_handler(ctx, payment_id, amount, crank_tip, interval_seconds, first_due_ts, end_ts)
+    }
+
+    pub fn cancel_scheduled_payment(ctx: Context<CancelScheduledPayment>) -> Result<()> {
+        cancel_scheduled_payment_handler(ctx)
+    }
+
+    pub fn execute_scheduled_payment<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteScheduledPayment<'info>>, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        execute_scheduled_payment_handler(ctx, price_update)
+    }
+
+    pub fn create_claimable_payment(
+        ctx: Context<CreateClaimablePayment>,
+        amount: u64,
+        expiry_ts: i64,
+        reduce_only: bool
+    ) -> Result<()> {
+        create_claimable_payment_handler(ctx, amount, expiry_ts, reduce_only)
+    }
+
+    pub fn claim_payment(ctx: Context<ClaimPayment>) -> Result<()> {
+        claim_payment_handler(ctx)
+    }
+
+    pub fn reclaim_payment(ctx: Context<ReclaimPayment>) -> Result<()> {
+        reclaim_payment_handler(ctx)
+    }
+
+    pub fn set_spend_rules(ctx: Context<SetSpendRules>, blocked_mccs: Vec<u16>, allowed_payees: Vec<Pubkey>, category_caps: Vec<CategoryCapParams>) -> Result<()> {
+        set_spend_rules_handler(ctx, blocked_mccs, allowed_payees, category_caps)
+    }
+
+    pub fn spend<'info>(ctx: Context<'_, '_, 'info, 'info, Spend<'info>>, spend_id: u64, amount: u64, mcc: u16, merchant_id: [u8; 32], price_update: Option<PythPriceUpdate>) -> Result<()> {
+        spend_handler(ctx, spend_id, amount, mcc, merchant_id, price_update)
+    }
+
+    pub fn set_vault_paused(ctx: Context<SetVaultPaused>, paused: bool) -> Result<()> {
+        set_vault_paused_handler(ctx, paused)
+    }
+
+    pub fn set_spend_limit(ctx: Context<SetSpendLimit>, limit_usd: u64, period_seconds: i64) -> Result<()> {
+        set_spend_limit_handler(ctx, limit_usd, period_seconds)
+    }
+
+    // Insurance fund
+
+    pub fn init_insurance_fund_stake(ctx: Context<InitInsuranceFundStake>) -> Result<()> {
+        init_insurance_fund_stake_handler(ctx)
+    }
+
+    pub fn stake_insurance_fund(ctx: Context<StakeInsuranceFund>, amount_micro_cents: u64) -> Result<()> {
+        stake_insurance_fund_handler(ctx, amount_micro_cents)
+    }
+
+    pub fn request_unstake_insurance_fund(ctx: Context<RequestUnstakeInsuranceFund>, amount_micro_cents: u64) -> Result<()> {
+        request_unstake_insurance_fund_handler(ctx, amount_micro_cents)
+    }
+
+    pub fn cancel_unstake_insurance_fund(ctx: Context<CancelUnstakeInsuranceFund>) -> Result<()> {
+        cancel_unstake_insurance_fund_handler(ctx)
+    }
+
+    pub fn unstake_insurance_fund(ctx: Context<UnstakeInsuranceFund>) -> Result<()> {
+        unstake_insurance_fund_handler(ctx)
+    }
+
+    // Orders
+
+    pub fn place_spot_order<'info>(ctx: Context<'_, '_, 'info, 'info, PlaceSpotOrder<'info>>, params: SpotOrderParams) -> Result<()> {
+        place_spot_order_handler(ctx, params)
+    }
+
+    pub fn modify_spot_order<'info>(ctx: Context<'_, '_, 'info, 'info, ModifySpotOrder<'info>>, order_id: u32, params: ModifySpotOrderParams) -> Result<()> {
+        modify_spot_order_handler(ctx, order_id, params)
+    }
+
+    pub fn cancel_spot_order<'info>(ctx: Context<'_, '_, 'info, 'info, CancelSpotOrder<'info>>, order_id: u32) -> Result<()> {
+        cancel_spot_order_handler(ctx, order_id)
+    }
+
+    pub fn cancel_spot_orders<'info>(ctx: Context<'_, '_, 'info, 'info, CancelSpotOrders<'info>>, market_index: Option<u16>) -> Result<()> {
+        cancel_spot_orders_handler(ctx, market_index)
+    }
+
+    pub fn create_dca_plan(ctx: Context<CreateDcaPlan>, plan_id: u64, params: DcaPlanParams) -> Result<()> {
+        create_dca_plan_handler(ctx, plan_id, params)
+    }
+
+    pub fn cancel_dca_plan(ctx: Context<CancelDcaPlan>) -> Result<()> {
+        cancel_dca_plan_handler(ctx)
+    }
+
+    pub fn execute_dca_plan<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteDcaPlan<'info>>) -> Result<()> {
+        execute_dca_plan_handler(ctx)
+    }
+
+    pub fn set_stop_loss(ctx: Context<SetStopLoss>, trigger_price: u64, base_asset_amount: u64) -> Result<()> {
+        set_stop_loss_handler(ctx, trigger_price, base_asset_amount)
+    }
+
+    pub fn cancel_stop_loss(ctx: Context<CancelStopLoss>) -> Result<()> {
+        cancel_stop_loss_handler(ctx)
+    }
+
+    // Hedge
+
+    pub fn set_hedge_mode(ctx: Context<SetHedgeMode>, hedge_ratio_bps: u16, hedge_keeper: Option<Pubkey>) -> Result<()> {
+        set_hedge_mode_handler(ctx, hedge_ratio_bps, hedge_keeper)
+    }
+
+    pub fn adjust_hedge(ctx: Context<AdjustHedge>) -> Result<()> {
+        adjust_hedge_handler(ctx)
+    }
+
+    pub fn settle_hedge_pnl(ctx: Context<SettleHedgePnl>) -> Result<()> {
+        settle_hedge_pnl_handler(ctx)
+    }
+
+    pub fn settle_hedge_funding(ctx: Context<SettleHedgeFunding>) -> Result<()> {
+        settle_hedge_funding_handler(ctx)
+    }
+
+    // Cards
+
+    pub fn issue_card(ctx: Context<IssueCard>, card_id: u64, limit_usd: u64, period_seconds: i64, expiry_ts: i64) -> Result<()> {
+        issue_card_handler(ctx, card_id, limit_usd, period_seconds, expiry_ts)
+    }
+
+    pub fn freeze_card(ctx: Context<FreezeCard>) -> Result<()> {
+        freeze_card_handler(ctx)
+    }
+
+    pub fn unfreeze_card(ctx: Context<UnfreezeCard>) -> Result<()> {
+        unfreeze_card_handler(ctx)
+    }
+
+    pub fn cancel_card(ctx: Context<CancelCard>) -> Result<()> {
+        cancel_card_handler(ctx)
+    }
+
+    // Team
+
+    pub fn set_team_mode(ctx: Context<SetTeamMode>, enabled: bool, approval_threshold_usd: u64) -> Result<()> {
+        set_team_mode_handler(ctx, enabled, approval_threshold_usd)
+    }
+
+    pub fn add_team_member(ctx: Context<AddTeamMember>, member: Pubkey, is_admin: bool, limit_usd: u64, period_seconds: i64) -> Result<()> {
+        add_team_member_handler(ctx, member, is_admin, limit_usd, period_seconds)
+    }
+
+    pub fn remove_team_member(ctx: Context<RemoveTeamMember>) -> Result<()> {
+        remove_team_member_handler(ctx)
+    }
+
+    pub fn request_team_spend(ctx: Context<RequestTeamSpend>, request_id: u64, amount: u64) -> Result<()> {
+        request_team_spend_handler(ctx, request_id, amount)
+    }
+
+    pub fn approve_team_spend(ctx: Context<ApproveTeamSpend>) -> Result<()> {
+        approve_team_spend_handler(ctx)
+    }
+
+    pub fn reject_team_spend(ctx: Context<RejectTeamSpend>) -> Result<()> {
+        reject_team_spend_handler(ctx)
+    }
+
+    pub fn team_spend<'info>(ctx: Context<'_, '_, 'info, 'info, TeamSpend<'info>>, spend_id: u64, amount: u64, price_update: Option<PythPriceUpdate>) -> Result<()> {
+        team_spend_handler(ctx, spend_id, amount, price_update)
+    }
+
+    // Child vaults
+
+    pub fn create_child_vault(ctx: Context<CreateChildVault>, child_owner: Pubkey, child_vault_id: u16, name: [u8; 32], limit_usd: u64, period_seconds: i64) -> Result<()> {
+        create_child_vault_handler(ctx, child_owner, child_vault_id, name, limit_usd, period_seconds)
+    }
+
+    pub fn set_child_limits(ctx: Context<SetChildLimits>, limit_usd: u64, period_seconds: i64) -> Result<()> {
+        set_child_limits_handler(ctx, limit_usd, period_seconds)
+    }
+
+    pub fn freeze_child_vault(ctx: Context<FreezeChildVault>) -> Result<()> {
+        freeze_child_vault_handler(ctx)
+    }
+
+    pub fn unfreeze_child_vault(ctx: Context<UnfreezeChildVault>) -> Result<()> {
+        unfreeze_child_vault_handler(ctx)
+    }
+
+    pub fn schedule_child_top_up(ctx: Context<ScheduleChildTopUp>, amount: u64, interval_seconds: i64, first_top_up_ts: i64) -> Result<()> {
+        schedule_child_top_up_handler(ctx, amount, interval_seconds, first_top_up_ts)
+    }
+
+    pub fn top_up_child_vault(ctx: Context<TopUpChildVault>) -> Result<()> {
+        top_up_child_vault_handler(ctx)
     }
 }
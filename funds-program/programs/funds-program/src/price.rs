@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use drift_cpi::{
+    accounts::SpotMarket as DriftSpotMarket,
+    OracleSource
+};
+use crate::{
+    constants::{
+        BPS_DENOMINATOR, MAX_ORACLE_CONFIDENCE_BPS, MAX_ORACLE_STALENESS_SECONDS, 
+        PRICE_PRECISION, PRICE_PRECISION_EXPONENT, PYTH_RECEIVER_PROGRAM_ID
+    },
+    errors::ErrorCode,
+    utils::find_spot_market
+};
+
+// Offsets into a Pyth receiver PriceUpdateV2 account, after the discriminator and write authority
+const PRICE_UPDATE_VERIFICATION_LEVEL_OFFSET: usize = 8 + 32;
+const PRICE_UPDATE_FULL_VERIFICATION: u8 = 1;
+const PRICE_UPDATE_MESSAGE_OFFSET: usize = PRICE_UPDATE_VERIFICATION_LEVEL_OFFSET + 1;
+const PRICE_UPDATE_FEED_ID_SIZE: usize = 32;
+
+struct PythPrice {
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N]> {
+    data.get(offset..offset + N)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(error!(ErrorCode::InvalidOracle))
+}
+
+fn load_pyth_price(oracle: &AccountInfo) -> Result<PythPrice> {
+    require_keys_eq!(*oracle.owner, PYTH_RECEIVER_PROGRAM_ID, ErrorCode::InvalidOracle);
+    let data = oracle.try_borrow_data()?;
+
+    // Partially verified updates were checked against only some of the Wormhole guardian signatures
+    let [verification_level] = read_bytes::<1>(&data, PRICE_UPDATE_VERIFICATION_LEVEL_OFFSET)?;
+    require!(verification_level == PRICE_UPDATE_FULL_VERIFICATION, ErrorCode::OracleNotFullyVerified);
+    let price_offset = PRICE_UPDATE_MESSAGE_OFFSET + PRICE_UPDATE_FEED_ID_SIZE;
+
+    Ok(PythPrice {
+        price: i64::from_le_bytes(read_bytes(&data, price_offset)?),
+        conf: u64::from_le_bytes(read_bytes(&data, price_offset + 8)?),
+        exponent: i32::from_le_bytes(read_bytes(&data, price_offset + 16)?),
+        publish_time: i64::from_le_bytes(read_bytes(&data, price_offset + 20)?)
+    })
+}
+
+fn scale_to_price_precision(value: u64, exponent: i32) -> Result<u64> {
+    let shift = PRICE_PRECISION_EXPONENT + exponent;
+    let factor = 10u128.checked_pow(shift.unsigned_abs()).ok_or(ErrorCode::MathOverflow)?;
+
+    let scaled = if shift >= 0 {
+        (value as u128).checked_mul(factor).ok_or(ErrorCode::MathOverflow)?
+    } else {
+        value as u128 / factor
+    };
+
+    u64::try_from(scaled).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+// USD price of one whole token with PRICE_PRECISION decimals, read from the oracle the Drift spot market uses
+pub fn get_oracle_price(spot_market: &DriftSpotMarket, oracle: &AccountInfo, current_ts: i64) -> Result<u64> {
+    require_keys_eq!(oracle.key(), spot_market.oracle, ErrorCode::InvalidOracle);
+
+    match spot_market.oracle_source {
+        OracleSource::QuoteAsset => return Ok(PRICE_PRECISION),
+        OracleSource::PythPull | OracleSource::PythStableCoinPull => {},
+        _ => return err!(ErrorCode::UnsupportedOracleSource)
+    }
+
+    let pyth_price = load_pyth_price(oracle)?;
+
+    require!(pyth_price.price > 0, ErrorCode::InvalidOracle);
+    require!(
+        current_ts.saturating_sub(pyth_price.publish_time) <= MAX_ORACLE_STALENESS_SECONDS, 
+        ErrorCode::StaleOracle
+    );
+
+    let price = pyth_price.price as u64;
+    let max_conf = (price as u128)
+        .checked_mul(MAX_ORACLE_CONFIDENCE_BPS as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    require!(pyth_price.conf as u128 <= max_conf, ErrorCode::OracleConfidenceTooWide);
+
+    scale_to_price_precision(price, pyth_price.exponent)
+}
+
+// USD value with PRICE_PRECISION decimals of a token amount in base units
+pub fn get_usd_value(token_amount: u64, decimals: u32, price: u64) -> Result<u64> {
+    let precision = 10u128.checked_pow(decimals).ok_or(ErrorCode::MathOverflow)?;
+
+    let value = (token_amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / precision;
+
+    u64::try_from(value).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+// Price of the spot market's token, with its oracle looked up among the accounts passed in
+pub fn get_spot_market_price(spot_market: &DriftSpotMarket, accounts: &[AccountInfo], current_ts: i64) -> Result<u64> {
+    if matches!(spot_market.oracle_source, OracleSource::QuoteAsset) {
+        return Ok(PRICE_PRECISION);
+    }
+
+    let oracle = accounts.iter()
+        .find(|account| account.key() == spot_market.oracle)
+        .ok_or(ErrorCode::InvalidOracle)?;
+    get_oracle_price(spot_market, oracle, current_ts)
+}
+
+// USD value of an amount in any Drift spot market, whose market and oracle must be among the accounts passed in
+pub fn get_spot_usd_value(
+    market_index: u16,
+    amount: u64,
+    accounts: &[AccountInfo],
+    current_ts: i64
+) -> Result<u64> {
+    let spot_market = find_spot_market(accounts, market_index)?;
+    let price = get_spot_market_price(&spot_market, accounts, current_ts)?;
+    get_usd_value(amount, spot_market.decimals, price)
+}
+
+// Converts an amount of one token into another with the same decimals, at the ratio of their prices
+pub fn convert_at_price(amount: u64, from_price: u64, to_price: u64) -> Result<u64> {
+    let converted = (amount as u128)
+        .checked_mul(from_price as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(to_price as u128)
+        .ok_or(ErrorCode::InvalidOracle)?;
+
+    u64::try_from(converted).map_err(|_| error!(ErrorCode::MathOverflow))
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed
+};
+use crate::constants::STAKE_POOL_PROGRAM_ID;
+
+// Minimal CPI into the SPL stake pool program, only the instant SOL deposit and withdraw paths are needed
+
+const DEPOSIT_SOL_INSTRUCTION: u8 = 14;
+const WITHDRAW_SOL_INSTRUCTION: u8 = 16;
+
+pub struct DepositSol<'info> {
+    pub stake_pool_program: AccountInfo<'info>,
+    pub stake_pool: AccountInfo<'info>,
+    pub withdraw_authority: AccountInfo<'info>,
+    pub reserve_stake: AccountInfo<'info>,
+    pub lamports_from: AccountInfo<'info>,
+    pub pool_tokens_to: AccountInfo<'info>,
+    pub manager_fee_account: AccountInfo<'info>,
+    pub referrer_pool_tokens: AccountInfo<'info>,
+    pub pool_mint: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>
+}
+
+pub struct WithdrawSol<'info> {
+    pub stake_pool_program: AccountInfo<'info>,
+    pub stake_pool: AccountInfo<'info>,
+    pub withdraw_authority: AccountInfo<'info>,
+    pub user_transfer_authority: AccountInfo<'info>,
+    pub pool_tokens_from: AccountInfo<'info>,
+    pub reserve_stake: AccountInfo<'info>,
+    pub lamports_to: AccountInfo<'info>,
+    pub manager_fee_account: AccountInfo<'info>,
+    pub pool_mint: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub stake_history: AccountInfo<'info>,
+    pub stake_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>
+}
+
+fn instruction_data(tag: u8, amount: u64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(9);
+    data.push(tag);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data
+}
+
+pub fn deposit_sol(accounts: DepositSol, lamports: u64) -> Result<()> {
+    let instruction = Instruction {
+        program_id: STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.stake_pool.key(), false),
+            AccountMeta::new_readonly(accounts.withdraw_authority.key(), false),
+            AccountMeta::new(accounts.reserve_stake.key(), false),
+            AccountMeta::new(accounts.lamports_from.key(), true),
+            AccountMeta::new(accounts.pool_tokens_to.key(), false),
+            AccountMeta::new(accounts.manager_fee_account.key(), false),
+            AccountMeta::new(accounts.referrer_pool_tokens.key(), false),
+            AccountMeta::new(accounts.pool_mint.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false)
+        ],
+        data: instruction_data(DEPOSIT_SOL_INSTRUCTION, lamports)
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.stake_pool,
+            accounts.withdraw_authority,
+            accounts.reserve_stake,
+            accounts.lamports_from,
+            accounts.pool_tokens_to,
+            accounts.manager_fee_account,
+            accounts.referrer_pool_tokens,
+            accounts.pool_mint,
+            accounts.system_program,
+            accounts.token_program,
+            accounts.stake_pool_program
+        ],
+        &[]
+    )?;
+
+    Ok(())
+}
+
+pub fn withdraw_sol(accounts: WithdrawSol, pool_tokens: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    let instruction = Instruction {
+        program_id: STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.stake_pool.key(), false),
+            AccountMeta::new_readonly(accounts.withdraw_authority.key(), false),
+            AccountMeta::new_readonly(accounts.user_transfer_authority.key(), true),
+            AccountMeta::new(accounts.pool_tokens_from.key(), false),
+            AccountMeta::new(accounts.reserve_stake.key(), false),
+            AccountMeta::new(accounts.lamports_to.key(), false),
+            AccountMeta::new(accounts.manager_fee_account.key(), false),
+            AccountMeta::new(accounts.pool_mint.key(), false),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(accounts.stake_history.key(), false),
+            AccountMeta::new_readonly(accounts.stake_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false)
+        ],
+        data: instruction_data(WITHDRAW_SOL_INSTRUCTION, pool_tokens)
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.stake_pool,
+            accounts.withdraw_authority,
+            accounts.user_transfer_authority,
+            accounts.pool_tokens_from,
+            accounts.reserve_stake,
+            accounts.lamports_to,
+            accounts.manager_fee_account,
+            accounts.pool_mint,
+            accounts.clock,
+            accounts.stake_history,
+            accounts.stake_program,
+            accounts.token_program,
+            accounts.stake_pool_program
+        ],
+        signer_seeds
+    )?;
+
+    Ok(())
+}
@@ -1,12 +1,635 @@
 use anchor_lang::prelude::*;
-use crate::constants::{ANCHOR_DISCRIMINATOR, PUBKEY_SIZE, U8_SIZE};
+use drift_cpi::PositionDirection;
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR, BOOL_SIZE, CATEGORY_CAP_PERIOD_SECONDS, I64_SIZE, 
+        MAX_ALLOWED_PAYEES, MAX_BLOCKED_MCCS, MAX_CATEGORY_CAPS, MAX_CO_OWNERS, MAX_GUARDIANS, 
+        MAX_VAULTS_PER_OWNER, MERCHANT_ID_SIZE, OPTION_PREFIX_SIZE, PUBKEY_SIZE, U16_SIZE, U64_SIZE, 
+        U8_SIZE, VAULT_RESERVED_SIZE, VAULT_VERSION, VEC_PREFIX_SIZE
+    },
+    errors::ErrorCode,
+    price::get_spot_usd_value,
+    utils::roll_period
+};
 
 #[account]
 pub struct Vault {
+    // First so any past layout can be told apart by the byte after the discriminator
+    pub version: u8,
     pub owner: Pubkey,
-    pub bump: u8
+    pub bump: u8,
+    // Key the vault PDA was derived from, unchanged when ownership is recovered
+    pub owner_seed: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub guardian_threshold: u8,
+    pub beneficiary: Option<Pubkey>,
+    pub inactivity_period_seconds: i64,
+    pub last_active_ts: i64,
+    pub insurance_fund_unstake_request_ts: i64,
+    pub drift_delegate: Option<Pubkey>,
+    // Zero means hedge mode is disabled
+    pub hedge_ratio_bps: u16,
+    pub hedge_keeper: Option<Pubkey>,
+    pub stop_loss: Option<StopLoss>,
+    // When set, SOL deposits are staked into the LST before going to Drift
+    pub lst_mode: bool,
+    pub blocked_mccs: Vec<u16>,
+    // Empty means any payee is allowed
+    pub allowed_payees: Vec<Pubkey>,
+    pub category_caps: Vec<CategoryCap>,
+    pub category_period_start_ts: i64,
+    // Stops every outflow that isn't signed by the primary owner, e.g. co-owner withdraws, scheduled payments and card spends
+    pub paused: bool,
+    // Caps outflows that aren't signed by the primary owner, None means no limit
+    pub spend_limit: Option<SpendLimit>,
+    pub team_mode: bool,
+    // USD, using PRICE_PRECISION. Team spends above this need an admin's approval
+    pub team_approval_threshold_usd: u64,
+    // Joint vaults only, any co-owner can operate the vault and withdraw within the spend limit
+    pub co_owners: Vec<Pubkey>,
+    // Owners, including the primary owner, that must sign to close the vault, change who controls it or change its limits
+    pub co_owner_threshold: u8,
+    // Zero for the owner's first vault
+    pub vault_id: u16,
+    // Child vaults only. The parent sets the spend limit and pause flag, the child owner can't withdraw directly
+    pub parent_vault: Option<Pubkey>,
+    // Room for new fields without another realloc, shrink it as fields are added
+    pub reserved: [u8; VAULT_RESERVED_SIZE]
 }
 
 impl Space for Vault {
-    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U8_SIZE;
-}
\ No newline at end of file
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + U8_SIZE + PUBKEY_SIZE + U8_SIZE + PUBKEY_SIZE
+        + VEC_PREFIX_SIZE + (MAX_GUARDIANS * PUBKEY_SIZE) + U8_SIZE
+        + OPTION_PREFIX_SIZE + PUBKEY_SIZE + I64_SIZE + I64_SIZE
+        + I64_SIZE + OPTION_PREFIX_SIZE + PUBKEY_SIZE
+        + U16_SIZE + OPTION_PREFIX_SIZE + PUBKEY_SIZE
+        + OPTION_PREFIX_SIZE + U64_SIZE + U64_SIZE + BOOL_SIZE
+        + VEC_PREFIX_SIZE + (MAX_BLOCKED_MCCS * U16_SIZE)
+        + VEC_PREFIX_SIZE + (MAX_ALLOWED_PAYEES * PUBKEY_SIZE)
+        + VEC_PREFIX_SIZE + (MAX_CATEGORY_CAPS * CategoryCap::SIZE) + I64_SIZE
+        + BOOL_SIZE + OPTION_PREFIX_SIZE + SpendLimit::SIZE
+        + BOOL_SIZE + U64_SIZE
+        + VEC_PREFIX_SIZE + (MAX_CO_OWNERS * PUBKEY_SIZE) + U8_SIZE
+        + U16_SIZE + OPTION_PREFIX_SIZE + PUBKEY_SIZE + VAULT_RESERVED_SIZE;
+}
+
+impl Vault {
+    pub fn new(owner: Pubkey, bump: u8, owner_seed: Pubkey, vault_id: u16) -> Self {
+        Self {
+            version: VAULT_VERSION,
+            owner,
+            bump,
+            owner_seed,
+            guardians: Vec::new(),
+            guardian_threshold: 0,
+            beneficiary: None,
+            inactivity_period_seconds: 0,
+            last_active_ts: 0,
+            insurance_fund_unstake_request_ts: 0,
+            drift_delegate: None,
+            hedge_ratio_bps: 0,
+            hedge_keeper: None,
+            stop_loss: None,
+            lst_mode: false,
+            blocked_mccs: Vec::new(),
+            allowed_payees: Vec::new(),
+            category_caps: Vec::new(),
+            category_period_start_ts: 0,
+            paused: false,
+            spend_limit: None,
+            team_mode: false,
+            team_approval_threshold_usd: 0,
+            co_owners: Vec::new(),
+            co_owner_threshold: 0,
+            vault_id,
+            parent_vault: None,
+            reserved: [0; VAULT_RESERVED_SIZE]
+        }
+    }
+
+    // Empty for the first vault, so vaults created before vault IDs keep their address
+    pub fn id_seed(vault_id: u16) -> Vec<u8> {
+        if vault_id == 0 {
+            Vec::new()
+        } else {
+            vault_id.to_le_bytes().to_vec()
+        }
+    }
+
+    pub fn vault_id_seed(&self) -> Vec<u8> {
+        Self::id_seed(self.vault_id)
+    }
+
+    // Called by every owner-signed instruction to reset the inheritance timer
+    pub fn record_activity(&mut self) -> Result<()> {
+        self.last_active_ts = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    pub fn is_owner(&self, signer: &Pubkey) -> bool {
+        self.owner == *signer || self.co_owners.contains(signer)
+    }
+
+    // Counts the distinct owners among the signer and any extra signers passed in the remaining accounts
+    pub fn require_owner_threshold(&self, signer: &Pubkey, other_signers: &[AccountInfo]) -> Result<()> {
+        if self.co_owners.is_empty() {
+            return Ok(());
+        }
+
+        let mut owners_signed = vec![*signer];
+        for account in other_signers.iter().filter(|account| account.is_signer) {
+            if self.is_owner(account.key) && !owners_signed.contains(account.key) {
+                owners_signed.push(*account.key);
+            }
+        }
+
+        require!(
+            owners_signed.len() >= self.co_owner_threshold as usize,
+            ErrorCode::CoOwnerThresholdNotMet
+        );
+        Ok(())
+    }
+
+    pub fn set_co_owners(&mut self, co_owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(co_owners.len() <= MAX_CO_OWNERS, ErrorCode::TooManyCoOwners);
+
+        // An empty co-owner list with a zero threshold makes it a single-owner vault again
+        require!(
+            (co_owners.is_empty() && threshold == 0)
+                || (threshold > 0 && (threshold as usize) <= co_owners.len() + 1),
+            ErrorCode::InvalidCoOwnerThreshold
+        );
+
+        for (i, co_owner) in co_owners.iter().enumerate() {
+            require!(
+                *co_owner != self.owner && !co_owners[..i].contains(co_owner),
+                ErrorCode::InvalidCoOwner
+            );
+        }
+
+        self.co_owners = co_owners;
+        self.co_owner_threshold = threshold;
+        Ok(())
+    }
+
+    pub fn is_child(&self) -> bool {
+        self.parent_vault.is_some()
+    }
+
+    pub fn is_child_of(&self, parent_vault: &Pubkey) -> bool {
+        self.parent_vault == Some(*parent_vault)
+    }
+
+    pub fn can_manage_hedge(&self, signer: &Pubkey) -> bool {
+        self.is_owner(signer) || self.hedge_keeper == Some(*signer)
+    }
+
+    // Team admins can approve spends but can't otherwise manage the vault
+    pub fn can_approve_team_spend(&self, signer: &Pubkey, member: Option<&TeamMember>) -> bool {
+        self.is_owner(signer) || member.is_some_and(|member| member.member == *signer && member.is_admin)
+    }
+
+    pub fn validate_payee(&self, payee: &Pubkey) -> Result<()> {
+        require!(
+            self.allowed_payees.is_empty() || self.allowed_payees.contains(payee),
+            ErrorCode::PayeeNotAllowed
+        );
+        Ok(())
+    }
+
+    // Checks a card spend against the merchant category rules, counting it towards the category's monthly cap
+    pub fn record_category_spend(&mut self, mcc: u16, amount_usd: u64, current_ts: i64) -> Result<()> {
+        require!(!self.blocked_mccs.contains(&mcc), ErrorCode::MerchantCategoryBlocked);
+
+        if let Some(period_start_ts) = roll_period(self.category_period_start_ts, CATEGORY_CAP_PERIOD_SECONDS, current_ts) {
+            self.category_period_start_ts = period_start_ts;
+            for cap in self.category_caps.iter_mut() {
+                cap.spent_in_period_usd = 0;
+            }
+        }
+
+        if let Some(cap) = self.category_caps.iter_mut().find(|cap| cap.mcc == mcc) {
+            let spent_in_period_usd = cap.spent_in_period_usd.checked_add(amount_usd)
+                .ok_or(ErrorCode::CategoryCapExceeded)?;
+            require!(spent_in_period_usd <= cap.monthly_cap_usd, ErrorCode::CategoryCapExceeded);
+            cap.spent_in_period_usd = spent_in_period_usd;
+        }
+
+        Ok(())
+    }
+
+    // A zero limit removes the spend limit
+    pub fn set_spend_limit(&mut self, limit_usd: u64, period_seconds: i64, current_ts: i64) -> Result<()> {
+        self.spend_limit = if limit_usd == 0 {
+            None
+        } else {
+            require!(period_seconds > 0, ErrorCode::InvalidSpendLimit);
+
+            // Spending already counted this period carries over while the period length is unchanged
+            let (spent_in_period_usd, period_start_ts) = match self.spend_limit {
+                Some(spend_limit) if spend_limit.period_seconds == period_seconds => {
+                    (spend_limit.spent_in_period_usd, spend_limit.period_start_ts)
+                },
+                _ => (0, current_ts)
+            };
+
+            Some(SpendLimit {
+                limit_usd,
+                spent_in_period_usd,
+                period_seconds,
+                period_start_ts
+            })
+        };
+        Ok(())
+    }
+
+    // Checks an outflow that isn't signed by an owner against the pause flag and the vault's spend limit
+    pub fn record_outflow(&mut self, amount_usd: u64, current_ts: i64) -> Result<()> {
+        require!(!self.paused, ErrorCode::VaultPaused);
+
+        if let Some(spend_limit) = self.spend_limit.as_mut() {
+            if let Some(period_start_ts) = roll_period(spend_limit.period_start_ts, spend_limit.period_seconds, current_ts) {
+                spend_limit.period_start_ts = period_start_ts;
+                spend_limit.spent_in_period_usd = 0;
+            }
+
+            let spent_in_period_usd = spend_limit.spent_in_period_usd.checked_add(amount_usd)
+                .ok_or(ErrorCode::SpendLimitExceeded)?;
+            require!(spent_in_period_usd <= spend_limit.limit_usd, ErrorCode::SpendLimitExceeded);
+            spend_limit.spent_in_period_usd = spent_in_period_usd;
+        }
+
+        Ok(())
+    }
+
+    // Only the primary owner withdraws freely, a co-owner's withdraw counts as an outflow
+    pub fn record_owner_withdraw(
+        &mut self,
+        signer: &Pubkey,
+        market_index: u16,
+        amount: u64,
+        market_accounts: &[AccountInfo],
+        current_ts: i64
+    ) -> Result<()> {
+        if *signer == self.owner {
+            return Ok(());
+        }
+
+        let amount_usd = get_spot_usd_value(market_index, amount, market_accounts, current_ts)?;
+        self.record_outflow(amount_usd, current_ts)
+    }
+}
+
+// Trigger market sell of SOL into USDC, executed by Drift keepers once the oracle price falls below trigger_price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct StopLoss {
+    pub trigger_price: u64,
+    pub base_asset_amount: u64
+}
+
+// USD amounts use PRICE_PRECISION
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CategoryCap {
+    pub mcc: u16,
+    pub monthly_cap_usd: u64,
+    pub spent_in_period_usd: u64
+}
+
+impl CategoryCap {
+    pub const SIZE: usize = U16_SIZE + U64_SIZE + U64_SIZE;
+}
+
+// USD amounts use PRICE_PRECISION
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SpendLimit {
+    pub limit_usd: u64,
+    pub spent_in_period_usd: u64,
+    pub period_seconds: i64,
+    pub period_start_ts: i64
+}
+
+impl SpendLimit {
+    pub const SIZE: usize = U64_SIZE + U64_SIZE + I64_SIZE + I64_SIZE;
+}
+
+#[account]
+pub struct RecoveryRequest {
+    pub vault: Pubkey,
+    pub new_owner: Pubkey,
+    pub initiator: Pubkey,
+    pub approvals: Vec<Pubkey>,
+    pub initiated_ts: i64,
+    pub bump: u8
+}
+
+impl Space for RecoveryRequest {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + PUBKEY_SIZE + PUBKEY_SIZE
+        + VEC_PREFIX_SIZE + (MAX_GUARDIANS * PUBKEY_SIZE) + I64_SIZE + U8_SIZE;
+}
+
+impl RecoveryRequest {
+    pub fn approval_count(&self, vault: &Vault) -> usize {
+        self.approvals.iter()
+            .filter(|approver| vault.guardians.contains(approver))
+            .count()
+    }
+}
+
+// Card allowances can only be spent through spend, where the card and merchant category rules apply
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AllowanceKind {
+    Payment,
+    Card
+}
+
+#[account]
+pub struct Allowance {
+    pub vault: Pubkey,
+    pub spender: Pubkey,
+    pub mint: Pubkey,
+    pub kind: AllowanceKind,
+    pub market_index: u16,
+    pub amount: u64,
+    pub spent_in_period: u64,
+    // Zero means the allowance never resets
+    pub period_seconds: i64,
+    pub period_start_ts: i64,
+    // Zero means the allowance never expires
+    pub expiry_ts: i64,
+    pub bump: u8
+}
+
+impl Space for Allowance {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + U8_SIZE + U16_SIZE
+        + U64_SIZE + U64_SIZE + I64_SIZE + I64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+impl Allowance {
+    pub fn consume(&mut self, amount: u64, current_ts: i64) -> Result<()> {
+        require!(
+            self.expiry_ts == 0 || current_ts < self.expiry_ts,
+            ErrorCode::AllowanceExpired
+        );
+
+        if let Some(period_start_ts) = roll_period(self.period_start_ts, self.period_seconds, current_ts) {
+            self.period_start_ts = period_start_ts;
+            self.spent_in_period = 0;
+        }
+
+        let spent_in_period = self.spent_in_period.checked_add(amount)
+            .ok_or(ErrorCode::AllowanceExceeded)?;
+        require!(spent_in_period <= self.amount, ErrorCode::AllowanceExceeded);
+        self.spent_in_period = spent_in_period;
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct ScheduledPayment {
+    pub vault: Pubkey,
+    pub payment_id: u64,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub market_index: u16,
+    pub amount: u64,
+    pub crank_tip: u64,
+    pub interval_seconds: i64,
+    pub next_due_ts: i64,
+    // Zero means the schedule never ends
+    pub end_ts: i64,
+    pub bump: u8
+}
+
+impl Space for ScheduledPayment {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U64_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + U16_SIZE
+        + U64_SIZE + U64_SIZE + I64_SIZE + I64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+#[account]
+pub struct ClaimablePayment {
+    pub vault: Pubkey,
+    // Ephemeral key shared in the payment link, only its signature can claim the payment
+    pub claim_authority: Pubkey,
+    // Owner that created the payment, receives the rent back
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub expiry_ts: i64,
+    pub bump: u8
+}
+
+impl Space for ClaimablePayment {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + PUBKEY_SIZE
+        + U64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell
+}
+
+impl From<OrderSide> for PositionDirection {
+    fn from(side: OrderSide) -> Self {
+        match side {
+            OrderSide::Buy => PositionDirection::Long,
+            OrderSide::Sell => PositionDirection::Short
+        }
+    }
+}
+
+#[account]
+pub struct DcaPlan {
+    pub vault: Pubkey,
+    pub plan_id: u64,
+    pub market_index: u16,
+    pub side: OrderSide,
+    // USD amounts use PRICE_PRECISION
+    pub amount_usd: u64,
+    // Caps each order's size in case the oracle price drops sharply
+    pub max_base_asset_amount: u64,
+    // Zero means orders are placed without a limit price
+    pub limit_price: u64,
+    pub interval_seconds: i64,
+    pub next_due_ts: i64,
+    // Zero means the plan never ends
+    pub end_ts: i64,
+    pub bump: u8
+}
+
+impl Space for DcaPlan {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U64_SIZE + U16_SIZE + U8_SIZE
+        + U64_SIZE + U64_SIZE + U64_SIZE + I64_SIZE + I64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+#[account]
+pub struct SpendRecord {
+    pub vault: Pubkey,
+    pub spend_id: u64,
+    pub spender: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub amount_usd: u64,
+    pub card_id: u64,
+    pub mcc: u16,
+    pub merchant_id: [u8; 32],
+    pub spend_ts: i64,
+    pub bump: u8
+}
+
+impl Space for SpendRecord {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U64_SIZE + PUBKEY_SIZE + PUBKEY_SIZE
+        + U64_SIZE + U64_SIZE + U64_SIZE + U16_SIZE + MERCHANT_ID_SIZE + I64_SIZE + U8_SIZE;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CardStatus {
+    Active,
+    Frozen,
+    Cancelled
+}
+
+#[account]
+pub struct Card {
+    pub vault: Pubkey,
+    pub card_id: u64,
+    pub status: CardStatus,
+    // USD amounts use PRICE_PRECISION
+    pub limit_usd: u64,
+    pub spent_in_period_usd: u64,
+    // Zero means the limit never resets, for single-use cards
+    pub period_seconds: i64,
+    pub period_start_ts: i64,
+    pub expiry_ts: i64,
+    pub bump: u8
+}
+
+impl Space for Card {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U64_SIZE + U8_SIZE
+        + U64_SIZE + U64_SIZE + I64_SIZE + I64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+impl Card {
+    pub fn consume(&mut self, amount_usd: u64, current_ts: i64) -> Result<()> {
+        require!(self.status == CardStatus::Active, ErrorCode::CardNotActive);
+        require!(current_ts < self.expiry_ts, ErrorCode::CardExpired);
+
+        if let Some(period_start_ts) = roll_period(self.period_start_ts, self.period_seconds, current_ts) {
+            self.period_start_ts = period_start_ts;
+            self.spent_in_period_usd = 0;
+        }
+
+        let spent_in_period_usd = self.spent_in_period_usd.checked_add(amount_usd)
+            .ok_or(ErrorCode::CardLimitExceeded)?;
+        require!(spent_in_period_usd <= self.limit_usd, ErrorCode::CardLimitExceeded);
+        self.spent_in_period_usd = spent_in_period_usd;
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct TeamMember {
+    pub vault: Pubkey,
+    pub member: Pubkey,
+    pub is_admin: bool,
+    // USD amounts use PRICE_PRECISION
+    pub limit_usd: u64,
+    pub spent_in_period_usd: u64,
+    // Zero means the limit never resets
+    pub period_seconds: i64,
+    pub period_start_ts: i64,
+    // Approvals requested before this belong to an earlier membership and can't be spent
+    pub added_ts: i64,
+    pub bump: u8
+}
+
+impl Space for TeamMember {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + PUBKEY_SIZE + BOOL_SIZE
+        + U64_SIZE + U64_SIZE + I64_SIZE + I64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+impl TeamMember {
+    pub fn consume(&mut self, amount_usd: u64, current_ts: i64) -> Result<()> {
+        if let Some(period_start_ts) = roll_period(self.period_start_ts, self.period_seconds, current_ts) {
+            self.period_start_ts = period_start_ts;
+            self.spent_in_period_usd = 0;
+        }
+
+        let spent_in_period_usd = self.spent_in_period_usd.checked_add(amount_usd)
+            .ok_or(ErrorCode::TeamMemberLimitExceeded)?;
+        require!(spent_in_period_usd <= self.limit_usd, ErrorCode::TeamMemberLimitExceeded);
+        self.spent_in_period_usd = spent_in_period_usd;
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct PendingApproval {
+    pub vault: Pubkey,
+    pub request_id: u64,
+    pub member: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub approver: Option<Pubkey>,
+    pub created_ts: i64,
+    pub bump: u8
+}
+
+impl Space for PendingApproval {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U64_SIZE + PUBKEY_SIZE + PUBKEY_SIZE
+        + U64_SIZE + OPTION_PREFIX_SIZE + PUBKEY_SIZE + I64_SIZE + U8_SIZE;
+}
+
+// Audit trail of every team spend, kept after the spend completes
+#[account]
+pub struct TeamSpendRecord {
+    pub vault: Pubkey,
+    pub spend_id: u64,
+    pub member: Pubkey,
+    // Set when the spend was above the approval threshold
+    pub approver: Option<Pubkey>,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub amount_usd: u64,
+    pub spend_ts: i64,
+    pub bump: u8
+}
+
+impl Space for TeamSpendRecord {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + U64_SIZE + PUBKEY_SIZE
+        + OPTION_PREFIX_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + U64_SIZE + U64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+// Scheduled top-ups from a parent vault to its child, moved by a parent withdraw followed by a child deposit
+#[account]
+pub struct ChildTopUp {
+    pub parent_vault: Pubkey,
+    pub child_vault: Pubkey,
+    pub market_index: u16,
+    pub amount: u64,
+    // Zero means no top-ups are scheduled
+    pub interval_seconds: i64,
+    pub next_top_up_ts: i64,
+    pub bump: u8
+}
+
+impl Space for ChildTopUp {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE + PUBKEY_SIZE
+        + U16_SIZE + U64_SIZE + I64_SIZE + I64_SIZE + U8_SIZE;
+}
+
+// Vault IDs an owner's wallet has created, vault 0 is the one from init_user
+#[account]
+pub struct OwnerRegistry {
+    pub owner: Pubkey,
+    pub vault_ids: Vec<u16>,
+    pub bump: u8
+}
+
+impl Space for OwnerRegistry {
+    const INIT_SPACE: usize = ANCHOR_DISCRIMINATOR + PUBKEY_SIZE
+        + VEC_PREFIX_SIZE + (MAX_VAULTS_PER_OWNER * U16_SIZE) + U8_SIZE;
+}
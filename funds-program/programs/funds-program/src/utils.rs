@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{
+    solana_program::program_pack::Pack,
+    system_program::{self, CreateAccount}
+};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_interface::{
+    self, 
+    spl_token_2022::{
+        self,
+        extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::{Account as Token2022Account, Mint as Token2022Mint}
+    },
+    InitializeAccount3,
+    TokenAccount
+};
+use drift_accounts::{
+    OrderStatus,
+    SpotBalanceType,
+    User as DriftUser
+};
+use drift_cpi::{
+    accounts::SpotMarket as DriftSpotMarket,
+    cpi::post_pyth_pull_oracle_update_atomic,
+    PostPythPullOracleUpdateAtomic
+};
+use crate::{
+    constants::{
+        DRIFT_MARKET_INDEX_PYUSD, DRIFT_MARKET_INDEX_SOL, DRIFT_MARKET_INDEX_USDC, DRIFT_PROGRAM_ID, 
+        DRIFT_SPOT_PRECISION_DECREASE_EXPONENT, PYUSD_MINT_ADDRESS, STOP_LOSS_USER_ORDER_ID, USDC_MINT_ADDRESS, 
+        WSOL_MINT_ADDRESS
+    },
+    errors::ErrorCode
+};
+
+pub fn get_drift_market_index(mint: &Pubkey) -> Result<u16> {
+    if *mint == USDC_MINT_ADDRESS {
+        Ok(DRIFT_MARKET_INDEX_USDC)
+    } else if *mint == WSOL_MINT_ADDRESS {
+        Ok(DRIFT_MARKET_INDEX_SOL)
+    } else if *mint == PYUSD_MINT_ADDRESS {
+        Ok(DRIFT_MARKET_INDEX_PYUSD)
+    } else {
+        err!(ErrorCode::InvalidMintAddress)
+    }
+}
+
+// Start of the period containing current_ts, or None while the current period hasn't ended.
+// A period of zero never rolls over
+pub fn roll_period(period_start_ts: i64, period_seconds: i64, current_ts: i64) -> Option<i64> {
+    if period_seconds <= 0 || current_ts < period_start_ts + period_seconds {
+        return None;
+    }
+
+    let elapsed_periods = (current_ts - period_start_ts) / period_seconds;
+    Some(period_start_ts + elapsed_periods * period_seconds)
+}
+
+// The quote market can't be traded against itself
+pub fn validate_spot_order_market(market_index: u16) -> Result<()> {
+    require!(market_index != DRIFT_MARKET_INDEX_USDC, ErrorCode::InvalidMarketIndex);
+    Ok(())
+}
+
+pub fn has_open_order_by_user_id(drift_user: &DriftUser, user_order_id: u8) -> bool {
+    drift_user.orders.iter().any(|order| 
+        order.status == OrderStatus::Open && order.user_order_id == user_order_id
+    )
+}
+
+// Stop-loss orders can only be changed through set_stop_loss and cancel_stop_loss
+pub fn validate_not_stop_loss_order(drift_user: &DriftUser, order_id: u32) -> Result<()> {
+    let is_stop_loss = drift_user.orders.iter().any(|order| 
+        order.status == OrderStatus::Open 
+            && order.order_id == order_id 
+            && order.user_order_id == STOP_LOSS_USER_ORDER_ID
+    );
+    require!(!is_stop_loss, ErrorCode::ReservedUserOrderId);
+    Ok(())
+}
+
+pub fn load_spot_market(spot_market: &AccountInfo) -> Result<DriftSpotMarket> {
+    require_keys_eq!(*spot_market.owner, DRIFT_PROGRAM_ID, ErrorCode::InvalidSpotMarket);
+    let data = spot_market.try_borrow_data()?;
+    DriftSpotMarket::try_deserialize(&mut &data[..])
+}
+
+// Finds the Drift spot market for the market index among the accounts passed in
+pub fn find_spot_market(accounts: &[AccountInfo], market_index: u16) -> Result<DriftSpotMarket> {
+    accounts.iter()
+        .filter(|account| *account.owner == DRIFT_PROGRAM_ID)
+        .filter_map(|account| load_spot_market(account).ok())
+        .find(|spot_market| spot_market.market_index == market_index)
+        .ok_or(error!(ErrorCode::InvalidSpotMarket))
+}
+
+// Token amount deposited in a Drift spot market, zero if the position is a borrow
+pub fn get_spot_deposit_amount(drift_user: &DriftUser, spot_market: &DriftSpotMarket) -> Result<u64> {
+    let Some(position) = drift_user.spot_positions.iter().find(|position| 
+        position.market_index == spot_market.market_index && position.scaled_balance > 0
+    ) else {
+        return Ok(0);
+    };
+
+    if position.balance_type == SpotBalanceType::Borrow {
+        return Ok(0);
+    }
+
+    let precision_decrease = 10u128.checked_pow(
+        DRIFT_SPOT_PRECISION_DECREASE_EXPONENT.checked_sub(spot_market.decimals)
+            .ok_or(ErrorCode::MathOverflow)?
+    ).ok_or(ErrorCode::MathOverflow)?;
+
+    let token_amount = (position.scaled_balance as u128)
+        .checked_mul(spot_market.cumulative_deposit_interest)
+        .ok_or(ErrorCode::MathOverflow)?
+        / precision_decrease;
+
+    u64::try_from(token_amount).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+// Creates the temporary [vault, mint] token account by hand, for instructions that can't declare it with init.
+// Works with either token program, Token-2022 accounts get the extensions their mint requires
+pub fn init_vault_token_account<'info>(
+    vault_spl: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>
+) -> Result<()> {
+    let (address, bump) = Pubkey::find_program_address(
+        &[vault.key.as_ref(), mint.key.as_ref()], 
+        &crate::ID
+    );
+    require_keys_eq!(address, vault_spl.key(), ErrorCode::InvalidVaultTokenAccount);
+
+    let seeds = &[
+        vault.key.as_ref(),
+        mint.key.as_ref(),
+        &[bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let space = if *token_program.key == spl_token_2022::ID {
+        let mint_data = mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)?;
+        let account_extensions = ExtensionType::get_required_init_account_extensions(
+            &mint_state.get_extension_types()?
+        );
+        ExtensionType::try_calculate_account_len::<Token2022Account>(&account_extensions)?
+    } else {
+        spl_token::state::Account::LEN
+    };
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: vault_spl.clone()
+            },
+            signer_seeds
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program.key
+    )?;
+
+    token_interface::initialize_account3(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeAccount3 {
+                account: vault_spl.clone(),
+                mint: mint.clone(),
+                authority: vault.clone()
+            }
+        )
+    )?;
+
+    Ok(())
+}
+
+// Balance of a token account owned by either token program
+pub fn get_token_account_amount(token_account: &AccountInfo) -> Result<u64> {
+    require!(
+        *token_account.owner == spl_token::ID || *token_account.owner == spl_token_2022::ID, 
+        ErrorCode::InvalidVaultTokenAccount
+    );
+    let data = token_account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
+
+// Accounts for one leg of a batch deposit or withdraw
+pub struct BatchLegAccounts<'info> {
+    pub mint: AccountInfo<'info>,
+    pub vault_spl: AccountInfo<'info>,
+    pub spot_market_vault: AccountInfo<'info>,
+    pub oracle: AccountInfo<'info>,
+    pub spot_market: AccountInfo<'info>,
+    pub owner_spl: Option<AccountInfo<'info>>
+}
+
+// Splits the remaining accounts into one group per leg, in order: [mint, vault_spl, spot_market_vault, oracle, spot_market]
+// followed by owner_spl for every mint except wSOL, which moves straight between the owner's lamports and the vault
+pub fn get_batch_leg_accounts<'info>(
+    leg_count: usize, 
+    remaining_accounts: &[AccountInfo<'info>]
+) -> Result<Vec<BatchLegAccounts<'info>>> {
+    let mut accounts = remaining_accounts.iter().cloned();
+    let mut legs = Vec::with_capacity(leg_count);
+
+    for _ in 0..leg_count {
+        let (Some(mint), Some(vault_spl), Some(spot_market_vault), Some(oracle), Some(spot_market)) = 
+            (accounts.next(), accounts.next(), accounts.next(), accounts.next(), accounts.next()) 
+        else {
+            return err!(ErrorCode::InvalidBatchAccounts);
+        };
+
+        let owner_spl = if *mint.key == WSOL_MINT_ADDRESS {
+            None
+        } else {
+            Some(accounts.next().ok_or(ErrorCode::InvalidBatchAccounts)?)
+        };
+
+        legs.push(BatchLegAccounts { mint, vault_spl, spot_market_vault, oracle, spot_market, owner_spl });
+    }
+
+    require!(accounts.next().is_none(), ErrorCode::InvalidBatchAccounts);
+    Ok(legs)
+}
+
+// Oracles then spot markets for every leg, so each Drift CPI in the batch sees all the markets involved
+pub fn get_batch_market_accounts<'info>(
+    mut oracles: Vec<AccountInfo<'info>>,
+    mut spot_markets: Vec<AccountInfo<'info>>,
+    legs: &[BatchLegAccounts<'info>]
+) -> Vec<AccountInfo<'info>> {
+    for leg in legs {
+        if !oracles.iter().any(|oracle| oracle.key == leg.oracle.key) {
+            oracles.push(leg.oracle.clone());
+        }
+        if !spot_markets.iter().any(|spot_market| spot_market.key == leg.spot_market.key) {
+            spot_markets.push(leg.spot_market.clone());
+        }
+    }
+
+    oracles.into_iter().chain(spot_markets).collect()
+}
+
+// Token-2022 accounts holding withheld transfer fees can't be closed until the fees are harvested to the mint
+pub fn mint_has_transfer_fee(mint: &AccountInfo) -> Result<bool> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(&data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().is_ok())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PythPriceUpdate {
+    pub feed_id: [u8; 32],
+    pub params: Vec<u8>
+}
+
+// Posts a Pyth pull-oracle update through Drift, so the Drift CPI that follows doesn't fail on a stale oracle
+pub fn post_price_update<'info>(
+    price_update: Option<PythPriceUpdate>,
+    keeper: &AccountInfo<'info>,
+    drift_program: &AccountInfo<'info>,
+    pyth_solana_receiver: Option<&UncheckedAccount<'info>>,
+    guardian_set: Option<&UncheckedAccount<'info>>,
+    price_feed: Option<&UncheckedAccount<'info>>
+) -> Result<()> {
+    let Some(price_update) = price_update else {
+        return Ok(());
+    };
+
+    let (Some(pyth_solana_receiver), Some(guardian_set), Some(price_feed)) = (pyth_solana_receiver, guardian_set, price_feed) else {
+        return err!(ErrorCode::MissingPriceUpdateAccounts);
+    };
+
+    let cpi_ctx = CpiContext::new(
+        drift_program.clone(),
+        PostPythPullOracleUpdateAtomic {
+            keeper: keeper.clone(),
+            pyth_solana_receiver: pyth_solana_receiver.to_account_info(),
+            guardian_set: guardian_set.to_account_info(),
+            price_feed: price_feed.to_account_info()
+        }
+    );
+
+    post_pyth_pull_oracle_update_atomic(cpi_ctx, price_update.feed_id, price_update.params)?;
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("allowance tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let spenderKeypair: Keypair;
+  let spenderWsol: PublicKey;
+  let allowancePda: PublicKey;
+  const allowanceAmount = LAMPORTS_PER_SOL / 2;
+  const pullAmount = LAMPORTS_PER_SOL / 10;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, connection, wallet, vaultPda, createFundedKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    spenderKeypair = await createFundedKeypair();
+    spenderWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, spenderKeypair.publicKey)).address;
+
+    [allowancePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("allowance"), vaultPda.toBuffer(), spenderKeypair.publicKey.toBuffer(), NATIVE_MINT.toBuffer()],
+      program.programId
+    );
+  });
+
+  const approveAllowance = async (owner: Keypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .approveAllowance(spenderKeypair.publicKey, NATIVE_MINT, { payment: {} }, new anchor.BN(allowanceAmount), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        allowance: allowancePda,
+        owner: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const revokeAllowance = async (owner: Keypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .revokeAllowance()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        allowance: allowancePda,
+        owner: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  it("approve_allowance by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await approveAllowance(otherOwnerKeypair);
+      assert.fail(0, 1, "approve_allowance instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("approve_allowance by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await approveAllowance(ownerKeypair);
+
+    const allowance = await program.account.allowance.fetch(allowancePda);
+    expect(allowance.vault.toBase58()).to.equal(vaultPda.toBase58());
+    expect(allowance.spender.toBase58()).to.equal(spenderKeypair.publicKey.toBase58());
+    expect(allowance.kind).to.deep.equal({ payment: {} });
+    expect(allowance.amount.toNumber()).to.equal(allowanceAmount);
+  });
+
+  it("pull_payment through a payment allowance", async () => {
+    const { program, provider, connection, vaultPda } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .pullPayment(new anchor.BN(pullAmount), null)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        allowance: allowancePda,
+        vaultSpl: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+        spender: spenderKeypair.publicKey,
+        spenderSpl: spenderWsol,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftUserStats: getDriftUserStats(vaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+        driftSigner: getDriftSigner(),
+        mint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        oracle: ORACLE_SOL,
+        pythSolanaReceiver: null,
+        guardianSet: null,
+        priceFeed: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([spenderKeypair])
+      .rpc();
+
+    const spenderAccount = await getAccount(connection, spenderWsol);
+    expect(Number(spenderAccount.amount)).to.equal(pullAmount);
+
+    const allowance = await program.account.allowance.fetch(allowancePda);
+    expect(allowance.spentInPeriod.toNumber()).to.equal(pullAmount);
+  });
+
+  it("revoke_allowance by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await revokeAllowance(otherOwnerKeypair);
+      assert.fail(0, 1, "revoke_allowance instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("revoke_allowance by owner", async () => {
+    const { connection, ownerKeypair } = testSetup;
+
+    await revokeAllowance(ownerKeypair);
+
+    const allowanceAccount = await connection.getAccountInfo(allowancePda);
+    expect(allowanceAccount).to.be.null;
+  });
+});
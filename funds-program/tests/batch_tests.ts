@@ -0,0 +1,190 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_PYUSD,
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+// Cloned from mainnet, see Anchor.toml
+const PYUSD_MINT = new PublicKey("2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo");
+
+describe("batch tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+
+  before(async () => {
+    testSetup = await setupTests();
+    await initDriftAccount(testSetup);
+  });
+
+  type Leg = { marketIndex: number, amount: number };
+
+  // One group per leg: mint, vault token account, Drift spot market vault, oracle, spot market, then the
+  // owner's token account for every mint except wSOL
+  const getLegAccounts = (mint: PublicKey, marketIndex: number, oracle: PublicKey, ownerSpl: PublicKey | null) => {
+    const { program, vaultPda } = testSetup;
+
+    const accounts = [
+      { pubkey: mint, isWritable: true, isSigner: false },
+      { pubkey: getVaultTokenAccount(program.programId, vaultPda, mint), isWritable: true, isSigner: false },
+      { pubkey: getSpotMarketVault(marketIndex), isWritable: true, isSigner: false },
+      { pubkey: oracle, isWritable: false, isSigner: false },
+      { pubkey: getSpotMarket(marketIndex), isWritable: true, isSigner: false },
+    ];
+    if (ownerSpl) accounts.push({ pubkey: ownerSpl, isWritable: true, isSigner: false });
+    return accounts;
+  };
+
+  const wsolLegAccounts = () => getLegAccounts(NATIVE_MINT, DRIFT_MARKET_INDEX_SOL, ORACLE_SOL, null);
+
+  const getBatchAccounts = (owner: PublicKey) => ({
+    vault: testSetup.vaultPda,
+    owner,
+    driftState: getDriftState(),
+    driftUser: getDriftUser(testSetup.vaultPda),
+    driftUserStats: getDriftUserStats(testSetup.vaultPda),
+    tokenProgram: TOKEN_PROGRAM_ID,
+    driftProgram: DRIFT_PROGRAM_ID,
+    constAccount: ORACLE_SOL,
+    additionalAccount: ORACLE_USDC,
+    spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+    spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+    systemProgram: SystemProgram.programId,
+    token2022Program: null,
+  });
+
+  const batchDeposit = async (
+    legs: Leg[], 
+    remainingAccounts: anchor.web3.AccountMeta[], 
+    signer: Keypair = testSetup.ownerKeypair
+  ) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .batchDeposit(legs.map(({ marketIndex, amount }) => ({ marketIndex, amount: new anchor.BN(amount), reduceOnly: false })))
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getBatchAccounts(signer.publicKey))
+      .remainingAccounts(remainingAccounts)
+      .signers([signer])
+      .rpc();
+  };
+
+  const batchWithdraw = async (
+    legs: Leg[], 
+    remainingAccounts: anchor.web3.AccountMeta[], 
+    signer: Keypair = testSetup.ownerKeypair
+  ) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .batchWithdraw(legs.map(({ marketIndex, amount }) => ({ marketIndex, amount: new anchor.BN(amount), reduceOnly: false })))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getBatchAccounts(signer.publicKey),
+        driftSigner: getDriftSigner(),
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([signer])
+      .rpc();
+  };
+
+  it("batch_deposit by non-owner", async () => {
+    try {
+      await batchDeposit([{ marketIndex: DRIFT_MARKET_INDEX_SOL, amount: LAMPORTS_PER_SOL }], wsolLegAccounts(), testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "batch_deposit instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("batch_deposit without legs", async () => {
+    try {
+      await batchDeposit([], []);
+      assert.fail(0, 1, "batch_deposit instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("EmptyBatch");
+    }
+  });
+
+  it("batch_deposit with a leg missing its accounts", async () => {
+    try {
+      await batchDeposit([{ marketIndex: DRIFT_MARKET_INDEX_SOL, amount: LAMPORTS_PER_SOL }], wsolLegAccounts().slice(0, 4));
+      assert.fail(0, 1, "batch_deposit instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidBatchAccounts");
+    }
+  });
+
+  it("batch_deposit a Token-2022 leg without the Token-2022 program", async () => {
+    const { ownerKeypair } = testSetup;
+    const ownerPyusd = anchor.utils.token.associatedAddress({ mint: PYUSD_MINT, owner: ownerKeypair.publicKey });
+
+    try {
+      await batchDeposit(
+        [{ marketIndex: DRIFT_MARKET_INDEX_PYUSD, amount: 1_000_000 }], 
+        getLegAccounts(PYUSD_MINT, DRIFT_MARKET_INDEX_PYUSD, ORACLE_USDC, ownerPyusd)
+      );
+      assert.fail(0, 1, "batch_deposit instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidBatchAccounts");
+    }
+  });
+
+  it("batch_deposit by owner", async () => {
+    const { connection, program, ownerKeypair, vaultPda } = testSetup;
+    const ownerBefore = await connection.getBalance(ownerKeypair.publicKey);
+
+    await batchDeposit([{ marketIndex: DRIFT_MARKET_INDEX_SOL, amount: LAMPORTS_PER_SOL }], wsolLegAccounts());
+
+    // The temporary token account's rent comes back when it's closed, leaving the deposit and fees
+    const ownerAfter = await connection.getBalance(ownerKeypair.publicKey);
+    expect(ownerBefore - ownerAfter).to.be.closeTo(LAMPORTS_PER_SOL, 100_000);
+
+    const vaultWsol = await connection.getAccountInfo(getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT));
+    expect(vaultWsol).to.be.null;
+  });
+
+  it("batch_withdraw by non-owner", async () => {
+    try {
+      await batchWithdraw([{ marketIndex: DRIFT_MARKET_INDEX_SOL, amount: LAMPORTS_PER_SOL / 2 }], wsolLegAccounts(), testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "batch_withdraw instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("batch_withdraw by owner", async () => {
+    const { connection, ownerKeypair } = testSetup;
+    const ownerBefore = await connection.getBalance(ownerKeypair.publicKey);
+
+    await batchWithdraw([{ marketIndex: DRIFT_MARKET_INDEX_SOL, amount: LAMPORTS_PER_SOL / 2 }], wsolLegAccounts());
+
+    // wSOL is unwrapped straight to the owner when the temporary account is closed
+    const ownerAfter = await connection.getBalance(ownerKeypair.publicKey);
+    expect(ownerAfter - ownerBefore).to.be.closeTo(LAMPORTS_PER_SOL / 2, 100_000);
+  });
+});
@@ -0,0 +1,266 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("card tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let cardManagerKeypair: Keypair;
+  let cardManagerWsol: PublicKey;
+  let cardPda: PublicKey;
+  const cardId = new anchor.BN(7);
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, connection, wallet, vaultPda, ownerKeypair, createFundedKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    cardManagerKeypair = await createFundedKeypair();
+    cardManagerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, cardManagerKeypair.publicKey)).address;
+
+    [cardPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("card"), vaultPda.toBuffer(), cardId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    const [allowancePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("allowance"), vaultPda.toBuffer(), cardManagerKeypair.publicKey.toBuffer(), NATIVE_MINT.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .approveAllowance(cardManagerKeypair.publicKey, NATIVE_MINT, { card: {} }, new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        allowance: allowancePda,
+        owner: ownerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  });
+
+  const setCardStatus = async (instruction: "freezeCard" | "unfreezeCard" | "cancelCard", owner = testSetup.ownerKeypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods[instruction]()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        card: cardPda,
+        owner: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const spend = async (spendId: number) => {
+    const { program, provider, vaultPda } = testSetup;
+    await refreshOracles(provider);
+
+    const spendIdBn = new anchor.BN(spendId);
+    const [spendRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("spend_record"), vaultPda.toBuffer(), spendIdBn.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [allowance] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("allowance"), vaultPda.toBuffer(), cardManagerKeypair.publicKey.toBuffer(), NATIVE_MINT.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .spend(spendIdBn, new anchor.BN(LAMPORTS_PER_SOL / 100), 5411, Array(32).fill(0), null)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        spendRecord,
+        card: cardPda,
+        allowance,
+        vaultSpl: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+        spender: cardManagerKeypair.publicKey,
+        spenderSpl: cardManagerWsol,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftUserStats: getDriftUserStats(vaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+        driftSigner: getDriftSigner(),
+        mint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        oracle: ORACLE_SOL,
+        pythSolanaReceiver: null,
+        guardianSet: null,
+        priceFeed: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cardManagerKeypair])
+      .rpc();
+  };
+
+  it("issue_card by non-owner", async () => {
+    const { program, vaultPda, otherOwnerKeypair } = testSetup;
+    const expiryTs = Math.floor(Date.now() / 1000) + 86_400;
+
+    try {
+      await program.methods
+        .issueCard(cardId, new anchor.BN(100_000_000), new anchor.BN(86_400), new anchor.BN(expiryTs))
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          card: cardPda,
+          owner: otherOwnerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([otherOwnerKeypair])
+        .rpc();
+      assert.fail(0, 1, "issue_card instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("issue_card by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+    const expiryTs = Math.floor(Date.now() / 1000) + 86_400;
+
+    await program.methods
+      .issueCard(cardId, new anchor.BN(100_000_000), new anchor.BN(86_400), new anchor.BN(expiryTs))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        card: cardPda,
+        owner: ownerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const card = await program.account.card.fetch(cardPda);
+    expect(card.status).to.deep.equal({ active: {} });
+    expect(card.limitUsd.toNumber()).to.equal(100_000_000);
+  });
+
+  it("unfreeze_card on an active card", async () => {
+    try {
+      await setCardStatus("unfreezeCard");
+      assert.fail(0, 1, "unfreeze_card instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("CardNotFrozen");
+    }
+  });
+
+  it("freeze_card by non-owner", async () => {
+    try {
+      await setCardStatus("freezeCard", testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "freeze_card instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("freeze_card by owner", async () => {
+    const { program } = testSetup;
+
+    await setCardStatus("freezeCard");
+
+    const card = await program.account.card.fetch(cardPda);
+    expect(card.status).to.deep.equal({ frozen: {} });
+  });
+
+  it("spend on a frozen card", async () => {
+    try {
+      await spend(1);
+      assert.fail(0, 1, "spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("CardNotActive");
+    }
+  });
+
+  it("unfreeze_card by owner", async () => {
+    const { program } = testSetup;
+
+    await setCardStatus("unfreezeCard");
+
+    const card = await program.account.card.fetch(cardPda);
+    expect(card.status).to.deep.equal({ active: {} });
+  });
+
+  it("spend on an unfrozen card", async () => {
+    const { program } = testSetup;
+
+    await spend(2);
+
+    // 0.01 SOL at $150 is $1.50
+    const card = await program.account.card.fetch(cardPda);
+    expect(card.spentInPeriodUsd.toNumber()).to.be.closeTo(1_500_000, 1_000);
+  });
+
+  it("cancel_card by non-owner", async () => {
+    try {
+      await setCardStatus("cancelCard", testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "cancel_card instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("cancel_card by owner", async () => {
+    const { program } = testSetup;
+
+    await setCardStatus("cancelCard");
+
+    const card = await program.account.card.fetch(cardPda);
+    expect(card.status).to.deep.equal({ cancelled: {} });
+  });
+
+  it("unfreeze_card on a cancelled card", async () => {
+    try {
+      await setCardStatus("unfreezeCard");
+      assert.fail(0, 1, "unfreeze_card instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("CardCancelled");
+    }
+  });
+
+  it("spend on a cancelled card", async () => {
+    try {
+      await spend(3);
+      assert.fail(0, 1, "spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("CardNotActive");
+    }
+  });
+});
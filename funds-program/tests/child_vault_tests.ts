@@ -0,0 +1,351 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("child vault tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let childOwnerKeypair: Keypair;
+  let childVaultPda: PublicKey;
+  let childTopUpPda: PublicKey;
+  const topUpAmount = LAMPORTS_PER_SOL / 10;
+  const topUpIntervalSeconds = 86_400;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, createFundedKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    childOwnerKeypair = await createFundedKeypair();
+    [childVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), childOwnerKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    [childTopUpPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("child_top_up"), childVaultPda.toBuffer()],
+      program.programId
+    );
+  });
+
+  const createChildVault = async (childOwner: PublicKey, signer: Keypair = testSetup.ownerKeypair) => {
+    const { program, vaultPda } = testSetup;
+    const [childVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), childOwner.toBuffer()],
+      program.programId
+    );
+    const [childTopUp] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("child_top_up"), childVault.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createChildVault(childOwner, 0, Array(32).fill(0), new anchor.BN(20_000_000), new anchor.BN(86_400))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        childVault,
+        childTopUp,
+        childOwnerRegistry: null,
+        owner: signer.publicKey,
+        childDriftUser: getDriftUser(childVault),
+        childDriftUserStats: getDriftUserStats(childVault),
+        driftState: getDriftState(),
+        driftProgram: DRIFT_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  };
+
+  const getParentAccounts = (childVault: PublicKey = childVaultPda) => ({
+    vault: testSetup.vaultPda,
+    childVault,
+    owner: testSetup.ownerKeypair.publicKey,
+  });
+
+  const setChildFrozen = async (frozen: boolean) => {
+    const { program, ownerKeypair } = testSetup;
+
+    await program.methods[frozen ? "freezeChildVault" : "unfreezeChildVault"]()
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getParentAccounts())
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const scheduleChildTopUp = async (amount: number, intervalSeconds: number) => {
+    const { program, ownerKeypair } = testSetup;
+
+    await program.methods
+      .scheduleChildTopUp(new anchor.BN(amount), new anchor.BN(intervalSeconds), new anchor.BN(Math.floor(Date.now() / 1000) - 10))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getParentAccounts(),
+        childTopUp: childTopUpPda,
+        mint: NATIVE_MINT,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const topUpChildVault = async (cranker: Keypair) => {
+    const { program, provider, vaultPda } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .topUpChildVault()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        childVault: childVaultPda,
+        childTopUp: childTopUpPda,
+        childSpl: getVaultTokenAccount(program.programId, childVaultPda, NATIVE_MINT),
+        cranker: cranker.publicKey,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftUserStats: getDriftUserStats(vaultPda),
+        childDriftUser: getDriftUser(childVaultPda),
+        childDriftUserStats: getDriftUserStats(childVaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+        driftSigner: getDriftSigner(),
+        mint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cranker])
+      .rpc();
+  };
+
+  it("create_child_vault by non-owner", async () => {
+    try {
+      await createChildVault(childOwnerKeypair.publicKey, testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "create_child_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("create_child_vault for the parent's owner", async () => {
+    try {
+      await createChildVault(testSetup.ownerKeypair.publicKey);
+      assert.fail(0, 1, "create_child_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidChildOwner");
+    }
+  });
+
+  it("create_child_vault by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await createChildVault(childOwnerKeypair.publicKey);
+
+    // The child is a normal vault at its owner's address, linked to the parent
+    const childVault = await program.account.vault.fetch(childVaultPda);
+    expect(childVault.owner.toBase58()).to.equal(childOwnerKeypair.publicKey.toBase58());
+    expect(childVault.parentVault.toBase58()).to.equal(vaultPda.toBase58());
+    expect(childVault.spendLimit.limitUsd.toNumber()).to.equal(20_000_000);
+  });
+
+  it("set_spend_limit by child owner", async () => {
+    const { program } = testSetup;
+
+    try {
+      await program.methods
+        .setSpendLimit(new anchor.BN(1_000_000_000), new anchor.BN(86_400))
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: childVaultPda,
+          owner: childOwnerKeypair.publicKey,
+        })
+        .signers([childOwnerKeypair])
+        .rpc();
+      assert.fail(0, 1, "set_spend_limit instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ManagedByParentVault");
+    }
+  });
+
+  it("set_guardians by child owner", async () => {
+    const { program } = testSetup;
+    const [recoveryRequest] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("recovery"), childVaultPda.toBuffer()],
+      program.programId
+    );
+
+    // Anyone the child names as a guardian could take the vault from under the parent
+    try {
+      await program.methods
+        .setGuardians([Keypair.generate().publicKey], 1)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: childVaultPda,
+          recoveryRequest,
+          owner: childOwnerKeypair.publicKey,
+        })
+        .signers([childOwnerKeypair])
+        .rpc();
+      assert.fail(0, 1, "set_guardians instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ManagedByParentVault");
+    }
+  });
+
+  it("set_child_limits on a vault that isn't a child", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .setChildLimits(new anchor.BN(50_000_000), new anchor.BN(86_400))
+        // @ts-ignore - Causing an issue in Cursor IDE
+        .accounts(getParentAccounts(vaultPda))
+        .signers([ownerKeypair])
+        .rpc();
+      assert.fail(0, 1, "set_child_limits instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAChildVault");
+    }
+  });
+
+  it("set_child_limits by parent owner", async () => {
+    const { program, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setChildLimits(new anchor.BN(50_000_000), new anchor.BN(86_400))
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getParentAccounts())
+      .signers([ownerKeypair])
+      .rpc();
+
+    const childVault = await program.account.vault.fetch(childVaultPda);
+    expect(childVault.spendLimit.limitUsd.toNumber()).to.equal(50_000_000);
+  });
+
+  it("freeze_child_vault by parent owner", async () => {
+    const { program } = testSetup;
+
+    await setChildFrozen(true);
+
+    const childVault = await program.account.vault.fetch(childVaultPda);
+    expect(childVault.paused).to.be.true;
+  });
+
+  it("set_vault_paused by child owner", async () => {
+    const { program } = testSetup;
+
+    try {
+      await program.methods
+        .setVaultPaused(false)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: childVaultPda,
+          owner: childOwnerKeypair.publicKey,
+        })
+        .signers([childOwnerKeypair])
+        .rpc();
+      assert.fail(0, 1, "set_vault_paused instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ManagedByParentVault");
+    }
+  });
+
+  it("freeze_child_vault on a frozen child", async () => {
+    try {
+      await setChildFrozen(true);
+      assert.fail(0, 1, "freeze_child_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ChildVaultFrozen");
+    }
+  });
+
+  it("unfreeze_child_vault by parent owner", async () => {
+    const { program } = testSetup;
+
+    await setChildFrozen(false);
+
+    const childVault = await program.account.vault.fetch(childVaultPda);
+    expect(childVault.paused).to.be.false;
+  });
+
+  it("schedule_child_top_up without an amount", async () => {
+    try {
+      await scheduleChildTopUp(0, topUpIntervalSeconds);
+      assert.fail(0, 1, "schedule_child_top_up instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidChildTopUp");
+    }
+  });
+
+  it("schedule_child_top_up by parent owner", async () => {
+    const { program } = testSetup;
+
+    await scheduleChildTopUp(topUpAmount, topUpIntervalSeconds);
+
+    const childTopUp = await program.account.childTopUp.fetch(childTopUpPda);
+    expect(childTopUp.marketIndex).to.equal(DRIFT_MARKET_INDEX_SOL);
+    expect(childTopUp.amount.toNumber()).to.equal(topUpAmount);
+  });
+
+  it("top_up_child_vault once due", async () => {
+    const { program, connection, createFundedKeypair } = testSetup;
+    const cranker = await createFundedKeypair();
+    const before = await program.account.childTopUp.fetch(childTopUpPda);
+
+    await topUpChildVault(cranker);
+
+    const after = await program.account.childTopUp.fetch(childTopUpPda);
+    expect(after.nextTopUpTs.toNumber()).to.equal(before.nextTopUpTs.toNumber() + topUpIntervalSeconds);
+
+    // The child's token account only holds the top-up between the parent withdraw and the child deposit
+    const childSpl = await connection.getAccountInfo(getVaultTokenAccount(program.programId, childVaultPda, NATIVE_MINT));
+    expect(childSpl).to.be.null;
+  });
+
+  it("top_up_child_vault before the next interval", async () => {
+    const { createFundedKeypair } = testSetup;
+    const cranker = await createFundedKeypair();
+
+    try {
+      await topUpChildVault(cranker);
+      assert.fail(0, 1, "top_up_child_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ChildTopUpNotDue");
+    }
+  });
+});
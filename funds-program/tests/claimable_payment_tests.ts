@@ -0,0 +1,215 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("claimable payment tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let recipientWsol: PublicKey;
+  let ownerWsol: PublicKey;
+  const paymentAmount = LAMPORTS_PER_SOL / 10;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { connection, wallet, ownerKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    recipientWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, Keypair.generate().publicKey)).address;
+    ownerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, ownerKeypair.publicKey)).address;
+  });
+
+  const getClaimablePaymentPdas = (claimAuthority: PublicKey) => {
+    const { program, vaultPda } = testSetup;
+    const [claimablePayment] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("claimable_payment"), vaultPda.toBuffer(), claimAuthority.toBuffer()],
+      program.programId
+    );
+    const [escrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), claimablePayment.toBuffer()],
+      program.programId
+    );
+    return { claimablePayment, escrow };
+  };
+
+  const createClaimablePayment = async (claimAuthority: PublicKey, expiryTs: number) => {
+    const { program, provider, vaultPda, ownerKeypair } = testSetup;
+    const { claimablePayment, escrow } = getClaimablePaymentPdas(claimAuthority);
+    await refreshOracles(provider);
+
+    await program.methods
+      .createClaimablePayment(new anchor.BN(paymentAmount), new anchor.BN(expiryTs), true)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        claimablePayment,
+        escrow,
+        claimAuthority,
+        owner: ownerKeypair.publicKey,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftUserStats: getDriftUserStats(vaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+        driftSigner: getDriftSigner(),
+        mint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const claimPayment = (claimAuthority: Keypair, paymentAuthority: PublicKey = claimAuthority.publicKey) => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+    const { claimablePayment, escrow } = getClaimablePaymentPdas(paymentAuthority);
+
+    return program.methods
+      .claimPayment()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        claimablePayment,
+        escrow,
+        claimAuthority: claimAuthority.publicKey,
+        creator: ownerKeypair.publicKey,
+        recipientSpl: recipientWsol,
+        mint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([claimAuthority])
+      .rpc();
+  };
+
+  const reclaimPayment = (claimAuthority: PublicKey) => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+    const { claimablePayment, escrow } = getClaimablePaymentPdas(claimAuthority);
+
+    return program.methods
+      .reclaimPayment()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        claimablePayment,
+        escrow,
+        owner: ownerKeypair.publicKey,
+        creator: ownerKeypair.publicKey,
+        ownerSpl: ownerWsol,
+        mint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  it("create_claimable_payment with an expiry in the past", async () => {
+    try {
+      await createClaimablePayment(Keypair.generate().publicKey, Math.floor(Date.now() / 1000) - 60);
+      assert.fail(0, 1, "create_claimable_payment instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidPaymentExpiry");
+    }
+  });
+
+  describe("claim", () => {
+    const claimAuthority = Keypair.generate();
+
+    before(async () => {
+      await createClaimablePayment(claimAuthority.publicKey, Math.floor(Date.now() / 1000) + 3600);
+    });
+
+    it("claim_payment signed by a key other than the link's", async () => {
+      try {
+        await claimPayment(Keypair.generate(), claimAuthority.publicKey);
+        assert.fail(0, 1, "claim_payment instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("ConstraintSeeds");
+      }
+    });
+
+    it("reclaim_payment before expiry", async () => {
+      try {
+        await reclaimPayment(claimAuthority.publicKey);
+        assert.fail(0, 1, "reclaim_payment instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("PaymentNotExpired");
+      }
+    });
+
+    it("claim_payment signed by the link's key", async () => {
+      const { program, connection, ownerKeypair } = testSetup;
+      const { claimablePayment, escrow } = getClaimablePaymentPdas(claimAuthority.publicKey);
+      const creatorBalanceBefore = await connection.getBalance(ownerKeypair.publicKey);
+
+      await claimPayment(claimAuthority);
+
+      const recipientAccount = await getAccount(connection, recipientWsol);
+      expect(Number(recipientAccount.amount)).to.equal(paymentAmount);
+      expect(await program.account.claimablePayment.fetchNullable(claimablePayment)).to.be.null;
+      expect(await connection.getAccountInfo(escrow)).to.be.null;
+
+      // Rent from both accounts goes back to the creator, not whoever sent the claim
+      expect(await connection.getBalance(ownerKeypair.publicKey)).to.be.greaterThan(creatorBalanceBefore);
+    });
+  });
+
+  describe("expiry", () => {
+    const claimAuthority = Keypair.generate();
+
+    before(async () => {
+      await createClaimablePayment(claimAuthority.publicKey, Math.floor(Date.now() / 1000) + 5);
+      await sleep(7_000);
+    });
+
+    it("claim_payment after expiry", async () => {
+      try {
+        await claimPayment(claimAuthority);
+        assert.fail(0, 1, "claim_payment instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("PaymentExpired");
+      }
+    });
+
+    it("reclaim_payment after expiry", async () => {
+      const { program, connection } = testSetup;
+      const { claimablePayment } = getClaimablePaymentPdas(claimAuthority.publicKey);
+      const ownerBalanceBefore = Number((await getAccount(connection, ownerWsol)).amount);
+
+      await reclaimPayment(claimAuthority.publicKey);
+
+      const ownerAccount = await getAccount(connection, ownerWsol);
+      expect(Number(ownerAccount.amount) - ownerBalanceBefore).to.equal(paymentAmount);
+      expect(await program.account.claimablePayment.fetchNullable(claimablePayment)).to.be.null;
+    });
+  });
+});
@@ -0,0 +1,107 @@
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import {
+  DRIFT_PROGRAM_ID,
+  getDriftUser,
+  initDriftAccount
+} from "./drift_utils";
+dotenv.config();
+
+describe("delegate tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  const delegate = Keypair.generate().publicKey;
+
+  before(async () => {
+    testSetup = await setupTests();
+    await initDriftAccount(testSetup);
+  });
+
+  const setDriftDelegate = (owner: Keypair, delegate: PublicKey) => {
+    const { program, vaultPda } = testSetup;
+
+    return program.methods
+      .setDriftDelegate(delegate)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: owner.publicKey,
+        driftUser: getDriftUser(vaultPda),
+        driftProgram: DRIFT_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const clearDriftDelegate = (owner: Keypair) => {
+    const { program, vaultPda } = testSetup;
+
+    return program.methods
+      .clearDriftDelegate()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: owner.publicKey,
+        driftUser: getDriftUser(vaultPda),
+        driftProgram: DRIFT_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  it("set_drift_delegate by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await setDriftDelegate(otherOwnerKeypair, delegate);
+      assert.fail(0, 1, "set_drift_delegate instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_drift_delegate to the owner", async () => {
+    const { ownerKeypair } = testSetup;
+
+    try {
+      await setDriftDelegate(ownerKeypair, ownerKeypair.publicKey);
+      assert.fail(0, 1, "set_drift_delegate instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidDriftDelegate");
+    }
+  });
+
+  it("set_drift_delegate by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await setDriftDelegate(ownerKeypair, delegate);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.driftDelegate.toBase58()).to.equal(delegate.toBase58());
+  });
+
+  it("clear_drift_delegate by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await clearDriftDelegate(otherOwnerKeypair);
+      assert.fail(0, 1, "clear_drift_delegate instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("clear_drift_delegate by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await clearDriftDelegate(ownerKeypair);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.driftDelegate).to.be.null;
+  });
+});
@@ -25,7 +25,7 @@ describe("drift tests", () => {
 
     try {
       await program.methods
-        .initDriftAccount()
+        .initDriftAccount(Array(32).fill(0))
         .accounts({
           // @ts-ignore - Causing an issue in Cursor IDE
           vault: vaultPda,
@@ -0,0 +1,139 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { setupTests } from "./setup_tests";
+import { ORACLE_LST, ORACLE_SOL, ORACLE_USDC, setPythPrice } from "./pyth_mock";
+
+// Drift and its markets are cloned from mainnet by the test validator, see Anchor.toml
+export const DRIFT_PROGRAM_ID = new PublicKey("dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH");
+
+// Matches QUARTZ_REFERRER, the default referrer for new Drift accounts
+export const QUARTZ_REFERRER = new PublicKey("5XY5pQbBjwv8ByBxKPNE7Xyb9dVcdFgd51xcxKDJjGWE");
+
+export const DRIFT_MARKET_INDEX_USDC = 0;
+export const DRIFT_MARKET_INDEX_SOL = 1;
+export const DRIFT_PERP_MARKET_INDEX_SOL = 0;
+export const DRIFT_MARKET_INDEX_LST = 6;
+export const DRIFT_MARKET_INDEX_PYUSD = 22;
+
+const toU16Buffer = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 2);
+
+export const getDriftState = () => PublicKey.findProgramAddressSync(
+  [Buffer.from("drift_state")],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getDriftSigner = () => PublicKey.findProgramAddressSync(
+  [Buffer.from("drift_signer")],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getDriftUser = (authority: PublicKey, subAccountId: number = 0) => PublicKey.findProgramAddressSync(
+  [Buffer.from("user"), authority.toBuffer(), toU16Buffer(subAccountId)],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getDriftUserStats = (authority: PublicKey) => PublicKey.findProgramAddressSync(
+  [Buffer.from("user_stats"), authority.toBuffer()],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getSpotMarket = (marketIndex: number) => PublicKey.findProgramAddressSync(
+  [Buffer.from("spot_market"), toU16Buffer(marketIndex)],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getPerpMarket = (marketIndex: number) => PublicKey.findProgramAddressSync(
+  [Buffer.from("perp_market"), toU16Buffer(marketIndex)],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getSpotMarketVault = (marketIndex: number) => PublicKey.findProgramAddressSync(
+  [Buffer.from("spot_market_vault"), toU16Buffer(marketIndex)],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getInsuranceFundVault = (marketIndex: number) => PublicKey.findProgramAddressSync(
+  [Buffer.from("insurance_fund_vault"), toU16Buffer(marketIndex)],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getInsuranceFundStake = (authority: PublicKey, marketIndex: number) => PublicKey.findProgramAddressSync(
+  [Buffer.from("insurance_fund_stake"), authority.toBuffer(), toU16Buffer(marketIndex)],
+  DRIFT_PROGRAM_ID
+)[0];
+
+export const getVaultTokenAccount = (programId: PublicKey, vault: PublicKey, mint: PublicKey) => PublicKey.findProgramAddressSync(
+  [vault.toBuffer(), mint.toBuffer()],
+  programId
+)[0];
+
+// Keeps the mock oracles inside the staleness window, Drift and the funds program both check it
+export const refreshOracles = async (provider: anchor.AnchorProvider) => {
+  const now = Math.floor(Date.now() / 1000);
+  await setPythPrice(provider, ORACLE_SOL, { price: 15_000_000_000, conf: 5_000_000, exponent: -8, publishTime: now });
+  await setPythPrice(provider, ORACLE_USDC, { price: 100_000_000, conf: 10_000, exponent: -8, publishTime: now });
+  await setPythPrice(provider, ORACLE_LST, { price: 17_250_000_000, conf: 5_000_000, exponent: -8, publishTime: now });
+};
+
+export const initDriftAccount = async (
+  testSetup: Awaited<ReturnType<typeof setupTests>>,
+  referrerUser: PublicKey | null = null,
+  referrerUserStats: PublicKey | null = null
+) => {
+  const { program, vaultPda, ownerKeypair } = testSetup;
+
+  await program.methods
+    .initDriftAccount(Array(32).fill(0))
+    .accounts({
+      // @ts-ignore - Causing an issue in Cursor IDE
+      vault: vaultPda,
+      owner: ownerKeypair.publicKey,
+      driftUser: getDriftUser(vaultPda),
+      driftUserStats: getDriftUserStats(vaultPda),
+      driftState: getDriftState(),
+      driftProgram: DRIFT_PROGRAM_ID,
+      referrerUser,
+      referrerUserStats,
+      quartzReferrerUser: getDriftUser(QUARTZ_REFERRER),
+      quartzReferrerUserStats: getDriftUserStats(QUARTZ_REFERRER),
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([ownerKeypair])
+    .rpc();
+};
+
+export const depositLamports = async (testSetup: Awaited<ReturnType<typeof setupTests>>, amount: number) => {
+  const { program, provider, vaultPda, ownerKeypair } = testSetup;
+  await refreshOracles(provider);
+
+  await program.methods
+    .depositLamports(new anchor.BN(amount), false)
+    .accounts({
+      // @ts-ignore - Causing an issue in Cursor IDE
+      vault: vaultPda,
+      vaultWsol: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+      owner: ownerKeypair.publicKey,
+      driftState: getDriftState(),
+      driftUser: getDriftUser(vaultPda),
+      driftUserStats: getDriftUserStats(vaultPda),
+      spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+      wsolMint: NATIVE_MINT,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      driftProgram: DRIFT_PROGRAM_ID,
+      constAccount: ORACLE_SOL,
+      spotMarket: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+      systemProgram: SystemProgram.programId,
+      vaultLst: null,
+      lstMint: null,
+      lstSpotMarketVault: null,
+      stakePool: null,
+      stakePoolWithdrawAuthority: null,
+      reserveStake: null,
+      managerFeeAccount: null,
+      stakePoolProgram: null,
+    })
+    .signers([ownerKeypair])
+    .rpc();
+};
@@ -0,0 +1,212 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PERP_MARKET_INDEX_SOL,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftState,
+  getDriftUser,
+  getPerpMarket,
+  getSpotMarket,
+  getSpotMarketVault,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("hedge tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let keeperKeypair: Keypair;
+  let otherVaultPda: PublicKey;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, otherOwnerKeypair } = testSetup;
+    keeperKeypair = await testSetup.createFundedKeypair();
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    // A second vault with its own Drift user, to pass where the first vault's is expected
+    [otherVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), otherOwnerKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .initUser()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: otherVaultPda,
+        owner: otherOwnerKeypair.publicKey,
+        ownerRegistry: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([otherOwnerKeypair])
+      .rpc();
+    await initDriftAccount({ ...testSetup, vaultPda: otherVaultPda, ownerKeypair: otherOwnerKeypair });
+  });
+
+  const setHedgeMode = async (hedgeRatioBps: number) => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setHedgeMode(hedgeRatioBps, keeperKeypair.publicKey)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const adjustHedge = async (authority: Keypair) => {
+    const { program, provider, vaultPda } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .adjustHedge()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        authority: authority.publicKey,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        perpMarketSol: getPerpMarket(DRIFT_PERP_MARKET_INDEX_SOL),
+        spotMarketLst: null,
+        oracleLst: null,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const settleHedgePnl = async (authority: Keypair) => {
+    const { program, provider, vaultPda } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .settleHedgePnl()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        authority: authority.publicKey,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_USDC),
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        perpMarketSol: getPerpMarket(DRIFT_PERP_MARKET_INDEX_SOL),
+        spotMarketLst: null,
+        oracleLst: null,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const settleHedgeFunding = async (driftUser: PublicKey) => {
+    const { program, vaultPda } = testSetup;
+    const crankerKeypair = await testSetup.createFundedKeypair();
+
+    await program.methods
+      .settleHedgeFunding()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        cranker: crankerKeypair.publicKey,
+        driftState: getDriftState(),
+        driftUser,
+        driftProgram: DRIFT_PROGRAM_ID,
+        perpMarketSol: getPerpMarket(DRIFT_PERP_MARKET_INDEX_SOL),
+      })
+      .signers([crankerKeypair])
+      .rpc();
+  };
+
+  it("set_hedge_mode above a full hedge", async () => {
+    try {
+      await setHedgeMode(10_001);
+      assert.fail(0, 1, "set_hedge_mode instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidHedgeRatio");
+    }
+  });
+
+  it("set_hedge_mode by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await setHedgeMode(5_000);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.hedgeRatioBps).to.equal(5_000);
+    expect(vault.hedgeKeeper.toBase58()).to.equal(keeperKeypair.publicKey.toBase58());
+  });
+
+  it("adjust_hedge by neither the owner nor the keeper", async () => {
+    const otherKeypair = await testSetup.createFundedKeypair();
+
+    try {
+      await adjustHedge(otherKeypair);
+      assert.fail(0, 1, "adjust_hedge instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidHedgeAuthority");
+    }
+  });
+
+  it("adjust_hedge by keeper", async () => {
+    const { connection, vaultPda } = testSetup;
+    const driftUserBefore = await connection.getAccountInfo(getDriftUser(vaultPda));
+
+    await adjustHedge(keeperKeypair);
+
+    // The short was placed on the vault's Drift user
+    const driftUserAfter = await connection.getAccountInfo(getDriftUser(vaultPda));
+    expect(driftUserAfter.data.equals(driftUserBefore.data)).to.be.false;
+  });
+
+  it("settle_hedge_pnl by neither the owner nor the keeper", async () => {
+    const otherKeypair = await testSetup.createFundedKeypair();
+
+    try {
+      await settleHedgePnl(otherKeypair);
+      assert.fail(0, 1, "settle_hedge_pnl instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidHedgeAuthority");
+    }
+  });
+
+  it("settle_hedge_pnl by keeper", async () => {
+    await settleHedgePnl(keeperKeypair);
+  });
+
+  it("settle_hedge_funding on another vault's Drift user", async () => {
+    try {
+      await settleHedgeFunding(getDriftUser(otherVaultPda));
+      assert.fail(0, 1, "settle_hedge_funding instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ConstraintSeeds");
+    }
+  });
+
+  it("settle_hedge_funding by any cranker", async () => {
+    await settleHedgeFunding(getDriftUser(testSetup.vaultPda));
+  });
+});
@@ -0,0 +1,139 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+dotenv.config();
+
+// Matches MIN_INACTIVITY_PERIOD_SECONDS with the local feature
+const MIN_INACTIVITY_PERIOD_SECONDS = 5;
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("inheritance tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let beneficiaryKeypair: Keypair;
+  let guardianKeypair: Keypair;
+  let recoveryPda: PublicKey;
+
+  before(async () => {
+    testSetup = await setupTests();
+    beneficiaryKeypair = await testSetup.createFundedKeypair();
+    guardianKeypair = await testSetup.createFundedKeypair();
+    [recoveryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("recovery"), testSetup.vaultPda.toBuffer()],
+      testSetup.program.programId
+    );
+  });
+
+  const setBeneficiary = async (inactivityPeriodSeconds: number, signer = testSetup.ownerKeypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .setBeneficiary(beneficiaryKeypair.publicKey, new anchor.BN(inactivityPeriodSeconds))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  };
+
+  const claimInheritance = async (initiator: PublicKey | null = null) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .claimInheritance()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        beneficiary: beneficiaryKeypair.publicKey,
+        initiator,
+      })
+      .signers([beneficiaryKeypair])
+      .rpc();
+  };
+
+  it("set_beneficiary by non-owner", async () => {
+    try {
+      await setBeneficiary(MIN_INACTIVITY_PERIOD_SECONDS, testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "set_beneficiary instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_beneficiary with too short an inactivity period", async () => {
+    try {
+      await setBeneficiary(MIN_INACTIVITY_PERIOD_SECONDS - 1);
+      assert.fail(0, 1, "set_beneficiary instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InactivityPeriodTooShort");
+    }
+  });
+
+  it("set_beneficiary by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await setBeneficiary(MIN_INACTIVITY_PERIOD_SECONDS);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.beneficiary.equals(beneficiaryKeypair.publicKey)).to.be.true;
+    expect(vault.inactivityPeriodSeconds.toNumber()).to.equal(MIN_INACTIVITY_PERIOD_SECONDS);
+  });
+
+  it("claim_inheritance while the owner is active", async () => {
+    try {
+      await claimInheritance();
+      assert.fail(0, 1, "claim_inheritance instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("OwnerStillActive");
+    }
+  });
+
+  it("claim_inheritance after the inactivity period with a pending recovery", async () => {
+    const { program, connection, vaultPda, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setGuardians([guardianKeypair.publicKey], 1)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    await program.methods
+      .initiateRecovery(Keypair.generate().publicKey)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        guardian: guardianKeypair.publicKey,
+      })
+      .signers([guardianKeypair])
+      .rpc();
+
+    await sleep((MIN_INACTIVITY_PERIOD_SECONDS + 1) * 1_000);
+
+    // The previous owner's guardians and their pending recovery don't carry over to the beneficiary
+    await claimInheritance(guardianKeypair.publicKey);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.owner.equals(beneficiaryKeypair.publicKey)).to.be.true;
+    expect(vault.beneficiary).to.be.null;
+    expect(vault.guardians).to.be.empty;
+    expect(vault.guardianThreshold).to.equal(0);
+
+    const recoveryRequestAccount = await connection.getAccountInfo(recoveryPda);
+    expect(recoveryRequestAccount).to.be.null;
+  });
+});
@@ -0,0 +1,195 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("joint vault tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let coOwnerKeypair: Keypair;
+  let vaultSeedKeypair: Keypair;
+  let jointVaultPda: PublicKey;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, createFundedKeypair } = testSetup;
+
+    coOwnerKeypair = await createFundedKeypair();
+    vaultSeedKeypair = Keypair.generate();
+    [jointVaultPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), vaultSeedKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+  });
+
+  const initJointVault = async (coOwners: PublicKey[], threshold: number) => {
+    const { program, ownerKeypair } = testSetup;
+
+    await program.methods
+      .initJointVault(coOwners, threshold)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: jointVaultPda,
+        vaultSeed: vaultSeedKeypair.publicKey,
+        owner: ownerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([vaultSeedKeypair, ownerKeypair])
+      .rpc();
+  };
+
+  const setCoOwners = async (owner: Keypair, coOwners: PublicKey[], threshold: number, otherSigners: Keypair[] = []) => {
+    const { program } = testSetup;
+
+    await program.methods
+      .setCoOwners(coOwners, threshold)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: jointVaultPda,
+        owner: owner.publicKey,
+      })
+      .remainingAccounts(otherSigners.map((signer) => ({ pubkey: signer.publicKey, isWritable: false, isSigner: true })))
+      .signers([owner, ...otherSigners])
+      .rpc();
+  };
+
+  it("init_joint_vault without co-owners", async () => {
+    try {
+      await initJointVault([], 1);
+      assert.fail(0, 1, "init_joint_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidCoOwner");
+    }
+  });
+
+  it("init_joint_vault by owner", async () => {
+    const { program, ownerKeypair } = testSetup;
+
+    await initJointVault([coOwnerKeypair.publicKey], 2);
+
+    const vault = await program.account.vault.fetch(jointVaultPda);
+    expect(vault.owner.toBase58()).to.equal(ownerKeypair.publicKey.toBase58());
+    expect(vault.ownerSeed.toBase58()).to.equal(vaultSeedKeypair.publicKey.toBase58());
+    expect(vault.coOwners.map((coOwner) => coOwner.toBase58())).to.deep.equal([coOwnerKeypair.publicKey.toBase58()]);
+    expect(vault.coOwnerThreshold).to.equal(2);
+  });
+
+  it("set_co_owners by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await setCoOwners(otherOwnerKeypair, [coOwnerKeypair.publicKey], 1);
+      assert.fail(0, 1, "set_co_owners instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_co_owners below the signing threshold", async () => {
+    const { ownerKeypair } = testSetup;
+
+    try {
+      await setCoOwners(ownerKeypair, [coOwnerKeypair.publicKey], 1);
+      assert.fail(0, 1, "set_co_owners instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("CoOwnerThresholdNotMet");
+    }
+  });
+
+  it("set_co_owners signed by the owner and a co-owner", async () => {
+    const { program, ownerKeypair } = testSetup;
+    const newCoOwner = Keypair.generate().publicKey;
+
+    await setCoOwners(ownerKeypair, [coOwnerKeypair.publicKey, newCoOwner], 2, [coOwnerKeypair]);
+
+    const vault = await program.account.vault.fetch(jointVaultPda);
+    expect(vault.coOwners.map((coOwner) => coOwner.toBase58())).to.deep.equal([
+      coOwnerKeypair.publicKey.toBase58(),
+      newCoOwner.toBase58()
+    ]);
+    expect(vault.coOwnerThreshold).to.equal(2);
+  });
+
+  it("set_spend_limit signed by the owner and a co-owner", async () => {
+    const { program, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setSpendLimit(new anchor.BN(1_000_000), new anchor.BN(86_400))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: jointVaultPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .remainingAccounts([{ pubkey: coOwnerKeypair.publicKey, isWritable: false, isSigner: true }])
+      .signers([ownerKeypair, coOwnerKeypair])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(jointVaultPda);
+    expect(vault.spendLimit.limitUsd.toNumber()).to.equal(1_000_000);
+  });
+
+  it("withdraw_lamports by a co-owner over the spend limit", async () => {
+    const { program, provider } = testSetup;
+    const jointTestSetup = { ...testSetup, vaultPda: jointVaultPda };
+
+    await initDriftAccount(jointTestSetup);
+    await depositLamports(jointTestSetup, LAMPORTS_PER_SOL);
+    await refreshOracles(provider);
+
+    // 0.1 SOL is worth more than the $1 limit, only the primary owner withdraws freely
+    try {
+      await program.methods
+        .withdrawLamports(new anchor.BN(LAMPORTS_PER_SOL / 10), false, null)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: jointVaultPda,
+          vaultWsol: getVaultTokenAccount(program.programId, jointVaultPda, NATIVE_MINT),
+          owner: coOwnerKeypair.publicKey,
+          driftState: getDriftState(),
+          driftUser: getDriftUser(jointVaultPda),
+          driftUserStats: getDriftUserStats(jointVaultPda),
+          spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+          driftSigner: getDriftSigner(),
+          wsolMint: NATIVE_MINT,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          driftProgram: DRIFT_PROGRAM_ID,
+          constAccount: ORACLE_SOL,
+          additionalAccount: ORACLE_USDC,
+          spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+          spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+          pythSolanaReceiver: null,
+          guardianSet: null,
+          priceFeed: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([coOwnerKeypair])
+        .rpc();
+      assert.fail(0, 1, "withdraw_lamports instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("SpendLimitExceeded");
+    }
+  });
+});
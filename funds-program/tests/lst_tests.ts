@@ -0,0 +1,241 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, SYSVAR_CLOCK_PUBKEY, SYSVAR_STAKE_HISTORY_PUBKEY, StakeProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_LST, ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import { LST_MINT, LST_STAKE_POOL, STAKE_POOL_PROGRAM_ID, getStakePoolReserve, getStakePoolWithdrawAuthority } from "./stake_pool_mock";
+import {
+  DRIFT_MARKET_INDEX_LST,
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("lst tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  // The stand-in charges no fees, so any writable account will do
+  const managerFeeAccount = Keypair.generate().publicKey;
+
+  before(async () => {
+    testSetup = await setupTests();
+    await initDriftAccount(testSetup);
+  });
+
+  const setLstMode = async (enabled: boolean, signer = testSetup.ownerKeypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .setLstMode(enabled)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  };
+
+  const depositLamports = async (amount: number, withStakePool = true) => {
+    const { program, provider, vaultPda, ownerKeypair } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .depositLamports(new anchor.BN(amount), false)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        vaultWsol: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+        owner: ownerKeypair.publicKey,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftUserStats: getDriftUserStats(vaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+        wsolMint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_LST,
+        spotMarket: getSpotMarket(DRIFT_MARKET_INDEX_LST),
+        systemProgram: SystemProgram.programId,
+        vaultLst: withStakePool ? getVaultTokenAccount(program.programId, vaultPda, LST_MINT) : null,
+        lstMint: withStakePool ? LST_MINT : null,
+        lstSpotMarketVault: withStakePool ? getSpotMarketVault(DRIFT_MARKET_INDEX_LST) : null,
+        stakePool: withStakePool ? LST_STAKE_POOL : null,
+        stakePoolWithdrawAuthority: withStakePool ? getStakePoolWithdrawAuthority() : null,
+        reserveStake: withStakePool ? getStakePoolReserve() : null,
+        managerFeeAccount: withStakePool ? managerFeeAccount : null,
+        stakePoolProgram: withStakePool ? STAKE_POOL_PROGRAM_ID : null,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const getStopLossAccounts = (withLstMarket: boolean) => {
+    const { vaultPda, ownerKeypair } = testSetup;
+
+    return {
+      vault: vaultPda,
+      owner: ownerKeypair.publicKey,
+      driftState: getDriftState(),
+      driftUser: getDriftUser(vaultPda),
+      driftProgram: DRIFT_PROGRAM_ID,
+      constAccount: ORACLE_SOL,
+      additionalAccount: ORACLE_USDC,
+      spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+      spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+      spotMarketLst: withLstMarket ? getSpotMarket(DRIFT_MARKET_INDEX_LST) : null,
+      oracleLst: withLstMarket ? ORACLE_LST : null,
+    };
+  };
+
+  const setStopLoss = async (withLstMarket: boolean) => {
+    const { program, provider, ownerKeypair } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .setStopLoss(new anchor.BN(100_000_000), new anchor.BN(LAMPORTS_PER_SOL / 10))
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getStopLossAccounts(withLstMarket))
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  it("set_lst_mode by non-owner", async () => {
+    try {
+      await setLstMode(true, testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "set_lst_mode instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_lst_mode by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await setLstMode(true);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.lstMode).to.be.true;
+  });
+
+  it("deposit_lamports in LST mode without the stake pool accounts", async () => {
+    try {
+      await depositLamports(LAMPORTS_PER_SOL, false);
+      assert.fail(0, 1, "deposit_lamports instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("MissingStakePoolAccounts");
+    }
+  });
+
+  it("deposit_lamports in LST mode stakes the SOL", async () => {
+    const { connection, program, vaultPda } = testSetup;
+    const reserveBefore = await connection.getBalance(getStakePoolReserve());
+
+    await depositLamports(LAMPORTS_PER_SOL);
+
+    const reserveAfter = await connection.getBalance(getStakePoolReserve());
+    expect(reserveAfter - reserveBefore).to.equal(LAMPORTS_PER_SOL);
+
+    // The temporary LST account is closed once Drift holds the deposit
+    const vaultLst = await connection.getAccountInfo(getVaultTokenAccount(program.programId, vaultPda, LST_MINT));
+    expect(vaultLst).to.be.null;
+  });
+
+  it("set_stop_loss in LST mode without the LST market", async () => {
+    try {
+      await setStopLoss(false);
+      assert.fail(0, 1, "set_stop_loss instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidSpotMarket");
+    }
+  });
+
+  it("set_stop_loss in LST mode", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await setStopLoss(true);
+
+    // The stop-loss is stored in SOL terms, the Drift order is converted to the LST
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.stopLoss.triggerPrice.toNumber()).to.equal(100_000_000);
+    expect(vault.stopLoss.baseAssetAmount.toNumber()).to.equal(LAMPORTS_PER_SOL / 10);
+  });
+
+  it("cancel_stop_loss in LST mode", async () => {
+    const { program, provider, vaultPda, ownerKeypair } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .cancelStopLoss()
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getStopLossAccounts(true))
+      .signers([ownerKeypair])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.stopLoss).to.be.null;
+  });
+
+  it("withdraw_lamports_lst unstakes to the owner", async () => {
+    const { connection, program, provider, vaultPda, ownerKeypair } = testSetup;
+    await refreshOracles(provider);
+    const lstAmount = LAMPORTS_PER_SOL / 2;
+    const reserveBefore = await connection.getBalance(getStakePoolReserve());
+
+    await program.methods
+      .withdrawLamportsLst(new anchor.BN(lstAmount), false, null)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        vaultLst: getVaultTokenAccount(program.programId, vaultPda, LST_MINT),
+        owner: ownerKeypair.publicKey,
+        stakePool: LST_STAKE_POOL,
+        stakePoolWithdrawAuthority: getStakePoolWithdrawAuthority(),
+        reserveStake: getStakePoolReserve(),
+        managerFeeAccount,
+        lstMint: LST_MINT,
+        stakePoolProgram: STAKE_POOL_PROGRAM_ID,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        stakeProgram: StakeProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftUserStats: getDriftUserStats(vaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_LST),
+        driftSigner: getDriftSigner(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        lstOracle: ORACLE_LST,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        spotMarketLst: getSpotMarket(DRIFT_MARKET_INDEX_LST),
+        pythSolanaReceiver: null,
+        guardianSet: null,
+        priceFeed: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    // The stand-in unstakes 1:1, paying the owner from its reserve
+    const reserveAfter = await connection.getBalance(getStakePoolReserve());
+    expect(reserveBefore - reserveAfter).to.equal(lstAmount);
+  });
+});
@@ -0,0 +1,96 @@
+import { AnchorError } from "@coral-xyz/anchor";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  getDriftUser,
+  getSpotMarket,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("margin tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+
+  before(async () => {
+    testSetup = await setupTests();
+    await initDriftAccount(testSetup);
+  });
+
+  const setCustomMarginRatio = (marginRatio: number) => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    return program.methods
+      .setCustomMarginRatio(marginRatio)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: ownerKeypair.publicKey,
+        driftUser: getDriftUser(vaultPda),
+        driftProgram: DRIFT_PROGRAM_ID,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  it("set_custom_margin_ratio above full collateral", async () => {
+    try {
+      await setCustomMarginRatio(10_001);
+      assert.fail(0, 1, "set_custom_margin_ratio instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidMarginRatio");
+    }
+  });
+
+  it("set_custom_margin_ratio by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await setCustomMarginRatio(5_000);
+
+    const driftUser = await program.provider.connection.getAccountInfo(getDriftUser(vaultPda));
+    expect(driftUser).to.not.be.null;
+  });
+
+  const setMarginTradingEnabled = (signer = testSetup.ownerKeypair, enabled = true) => {
+    const { program, vaultPda } = testSetup;
+
+    return program.methods
+      .setMarginTradingEnabled(enabled)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: signer.publicKey,
+        driftUser: getDriftUser(vaultPda),
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+      })
+      .signers([signer])
+      .rpc();
+  };
+
+  it("set_margin_trading_enabled by non-owner", async () => {
+    try {
+      await setMarginTradingEnabled(testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "set_margin_trading_enabled instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_margin_trading_enabled on and off by owner", async () => {
+    await refreshOracles(testSetup.provider);
+
+    await setMarginTradingEnabled(testSetup.ownerKeypair, true);
+    await setMarginTradingEnabled(testSetup.ownerKeypair, false);
+  });
+});
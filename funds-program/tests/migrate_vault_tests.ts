@@ -0,0 +1,77 @@
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+dotenv.config();
+
+// Loaded from fixtures, see Anchor.toml
+const LEGACY_VAULT = new PublicKey("BjugMPa1a8CPfgMiYZyMzZwcCuhfkAvn2uhHXWbkysEK");
+const LEGACY_VAULT_OWNER = new PublicKey("4RgGzhutRbCGoCzdcUNyC2qvViUwEhFetg1XN7yx9m64");
+const LEGACY_VAULT_SIZE = 41;
+const VAULT_VERSION = 1;
+
+describe("migrate_vault tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let sponsorKeypair: Keypair;
+
+  before(async () => {
+    testSetup = await setupTests();
+    sponsorKeypair = await testSetup.createFundedKeypair();
+  });
+
+  const migrateVault = async (vault: PublicKey) => {
+    const { program } = testSetup;
+
+    await program.methods
+      .migrateVault()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault,
+        payer: sponsorKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([sponsorKeypair])
+      .rpc();
+  };
+
+  it("migrate_vault on a vault already on the latest version", async () => {
+    try {
+      await migrateVault(testSetup.vaultPda);
+      assert.fail(0, 1, "migrate_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("VaultAlreadyMigrated");
+    }
+  });
+
+  it("migrate_vault reallocs a legacy vault", async () => {
+    const { program, connection, vaultPda } = testSetup;
+    const legacyVault = await connection.getAccountInfo(LEGACY_VAULT);
+    expect(legacyVault.data.length).to.equal(LEGACY_VAULT_SIZE);
+
+    await migrateVault(LEGACY_VAULT);
+
+    // Same size as a vault created on the latest version, with the sponsor covering the extra rent
+    const currentVault = await connection.getAccountInfo(vaultPda);
+    const migratedVault = await connection.getAccountInfo(LEGACY_VAULT);
+    expect(migratedVault.data.length).to.equal(currentVault.data.length);
+    expect(migratedVault.lamports).to.equal(await connection.getMinimumBalanceForRentExemption(currentVault.data.length));
+
+    const vault = await program.account.vault.fetch(LEGACY_VAULT);
+    expect(vault.version).to.equal(VAULT_VERSION);
+    expect(vault.owner.toBase58()).to.equal(LEGACY_VAULT_OWNER.toBase58());
+    expect(vault.ownerSeed.toBase58()).to.equal(LEGACY_VAULT_OWNER.toBase58());
+    expect(vault.vaultId).to.equal(0);
+  });
+
+  it("migrate_vault on a migrated vault", async () => {
+    try {
+      await migrateVault(LEGACY_VAULT);
+      assert.fail(0, 1, "migrate_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("VaultAlreadyMigrated");
+    }
+  });
+});
@@ -0,0 +1,25 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey
+};
+
+// Loaded at the Pyth receiver address on localnet, so tests can overwrite the PriceUpdateV2 oracle fixtures it owns.
+// The instruction data is written over the start of the first account, with no other checks.
+entrypoint!(process_instruction);
+
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let price_update = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if price_update.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut price_update_data = price_update.try_borrow_mut_data()?;
+    price_update_data.get_mut(..data.len())
+        .ok_or(ProgramError::AccountDataTooSmall)?
+        .copy_from_slice(data);
+
+    Ok(())
+}
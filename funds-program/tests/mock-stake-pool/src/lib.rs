@@ -0,0 +1,120 @@
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction
+};
+
+// Loaded at the SPL stake pool address on localnet. The pool's withdraw authority is a PDA of this address,
+// so the stand-in can mint the cloned LST as its mint authority. Pool tokens are exchanged 1:1 for lamports,
+// held by a reserve account this program owns, and there are no fees.
+entrypoint!(process_instruction);
+
+const DEPOSIT_SOL_INSTRUCTION: u8 = 14;
+const WITHDRAW_SOL_INSTRUCTION: u8 = 16;
+
+const TOKEN_MINT_TO_INSTRUCTION: u8 = 7;
+const TOKEN_BURN_INSTRUCTION: u8 = 8;
+
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (&tag, amount) = data.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+    let amount = amount.try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    match tag {
+        DEPOSIT_SOL_INSTRUCTION => deposit_sol(program_id, accounts, amount),
+        WITHDRAW_SOL_INSTRUCTION => withdraw_sol(program_id, accounts, amount),
+        _ => Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+fn check_pool_accounts(
+    program_id: &Pubkey,
+    stake_pool: &AccountInfo,
+    withdraw_authority: &AccountInfo,
+    reserve_stake: &AccountInfo
+) -> Result<u8, ProgramError> {
+    let (expected_withdraw_authority, bump) = Pubkey::find_program_address(
+        &[stake_pool.key.as_ref(), b"withdraw"], 
+        program_id
+    );
+    if *withdraw_authority.key != expected_withdraw_authority {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let (expected_reserve, _) = Pubkey::find_program_address(&[stake_pool.key.as_ref(), b"reserve"], program_id);
+    if *reserve_stake.key != expected_reserve || reserve_stake.owner != program_id {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump)
+}
+
+fn token_instruction(
+    token_program: &AccountInfo,
+    tag: u8,
+    amount: u64,
+    accounts: Vec<AccountMeta>
+) -> Instruction {
+    let mut data = vec![tag];
+    data.extend_from_slice(&amount.to_le_bytes());
+    Instruction { program_id: *token_program.key, accounts, data }
+}
+
+// Accounts: stake pool, withdraw authority, reserve, lamports from, pool tokens to, manager fee, referrer, 
+// pool mint, system program, token program
+fn deposit_sol(program_id: &Pubkey, accounts: &[AccountInfo], lamports: u64) -> ProgramResult {
+    let [stake_pool, withdraw_authority, reserve_stake, lamports_from, pool_tokens_to, _manager_fee, _referrer, pool_mint, system_program, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let bump = check_pool_accounts(program_id, stake_pool, withdraw_authority, reserve_stake)?;
+
+    invoke(
+        &system_instruction::transfer(lamports_from.key, reserve_stake.key, lamports),
+        &[lamports_from.clone(), reserve_stake.clone(), system_program.clone()]
+    )?;
+
+    invoke_signed(
+        &token_instruction(token_program, TOKEN_MINT_TO_INSTRUCTION, lamports, vec![
+            AccountMeta::new(*pool_mint.key, false),
+            AccountMeta::new(*pool_tokens_to.key, false),
+            AccountMeta::new_readonly(*withdraw_authority.key, true)
+        ]),
+        &[pool_mint.clone(), pool_tokens_to.clone(), withdraw_authority.clone(), token_program.clone()],
+        &[&[stake_pool.key.as_ref(), b"withdraw", &[bump]]]
+    )
+}
+
+// Accounts: stake pool, withdraw authority, user transfer authority, pool tokens from, reserve, lamports to,
+// manager fee, pool mint, clock, stake history, stake program, token program
+fn withdraw_sol(program_id: &Pubkey, accounts: &[AccountInfo], pool_tokens: u64) -> ProgramResult {
+    let [stake_pool, withdraw_authority, user_transfer_authority, pool_tokens_from, reserve_stake, lamports_to, _manager_fee, pool_mint, _clock, _stake_history, _stake_program, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_pool_accounts(program_id, stake_pool, withdraw_authority, reserve_stake)?;
+
+    invoke(
+        &token_instruction(token_program, TOKEN_BURN_INSTRUCTION, pool_tokens, vec![
+            AccountMeta::new(*pool_tokens_from.key, false),
+            AccountMeta::new(*pool_mint.key, false),
+            AccountMeta::new_readonly(*user_transfer_authority.key, true)
+        ]),
+        &[pool_tokens_from.clone(), pool_mint.clone(), user_transfer_authority.clone(), token_program.clone()]
+    )?;
+
+    // The reserve is owned by this program, so its lamports can be moved directly
+    let reserve_lamports = reserve_stake.lamports()
+        .checked_sub(pool_tokens)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **reserve_stake.try_borrow_mut_lamports()? = reserve_lamports;
+    **lamports_to.try_borrow_mut_lamports()? = lamports_to.lamports()
+        .checked_add(pool_tokens)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
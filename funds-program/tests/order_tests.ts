@@ -0,0 +1,399 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftState,
+  getDriftUser,
+  getSpotMarket,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+// Drift numbers a user's orders from 1, in the order they're placed
+const STOP_LOSS_ORDER_ID = 1;
+const LIMIT_ORDER_ID = 2;
+const STOP_LOSS_USER_ORDER_ID = 255;
+
+describe("order tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+
+  before(async () => {
+    testSetup = await setupTests();
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+  });
+
+  // Oracles first, then the spot markets Drift needs for the margin check
+  const orderRemainingAccounts = () => [
+    { pubkey: ORACLE_SOL, isWritable: false, isSigner: false },
+    { pubkey: ORACLE_USDC, isWritable: false, isSigner: false },
+    { pubkey: getSpotMarket(DRIFT_MARKET_INDEX_USDC), isWritable: true, isSigner: false },
+    { pubkey: getSpotMarket(DRIFT_MARKET_INDEX_SOL), isWritable: true, isSigner: false },
+  ];
+
+  const getOrderAccounts = (owner: PublicKey) => ({
+    vault: testSetup.vaultPda,
+    owner,
+    driftState: getDriftState(),
+    driftUser: getDriftUser(testSetup.vaultPda),
+    driftProgram: DRIFT_PROGRAM_ID,
+  });
+
+  const placeSpotOrder = async (userOrderId: number, signer = testSetup.ownerKeypair) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .placeSpotOrder({
+        marketIndex: DRIFT_MARKET_INDEX_SOL,
+        side: { sell: {} },
+        orderType: { limit: {} },
+        userOrderId,
+        baseAssetAmount: new anchor.BN(LAMPORTS_PER_SOL / 10),
+        // $1,000 is far above the oracle price, so the order rests on the book
+        price: new anchor.BN(1_000_000_000),
+        reduceOnly: false,
+        postOnly: true,
+        maxTs: null,
+      })
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getOrderAccounts(signer.publicKey))
+      .remainingAccounts(orderRemainingAccounts())
+      .signers([signer])
+      .rpc();
+  };
+
+  const setStopLoss = async () => {
+    const { program, provider, ownerKeypair } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .setStopLoss(new anchor.BN(50_000_000), new anchor.BN(LAMPORTS_PER_SOL / 10))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getOrderAccounts(ownerKeypair.publicKey),
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        spotMarketLst: null,
+        oracleLst: null,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const modifySpotOrder = async (orderId: number) => {
+    const { program, provider, ownerKeypair } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .modifySpotOrder(orderId, { baseAssetAmount: null, price: new anchor.BN(900_000_000), maxTs: null })
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getOrderAccounts(ownerKeypair.publicKey))
+      .remainingAccounts(orderRemainingAccounts())
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const cancelSpotOrder = async (orderId: number) => {
+    const { program, provider, ownerKeypair } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .cancelSpotOrder(orderId)
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getOrderAccounts(ownerKeypair.publicKey))
+      .remainingAccounts(orderRemainingAccounts())
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  const cancelStopLoss = async (signer = testSetup.ownerKeypair) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .cancelStopLoss()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getOrderAccounts(signer.publicKey),
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        spotMarketLst: null,
+        oracleLst: null,
+      })
+      .signers([signer])
+      .rpc();
+  };
+
+  it("place_spot_order by non-owner", async () => {
+    try {
+      await placeSpotOrder(1, testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "place_spot_order instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("place_spot_order with the stop-loss user order id", async () => {
+    try {
+      await placeSpotOrder(STOP_LOSS_USER_ORDER_ID);
+      assert.fail(0, 1, "place_spot_order instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ReservedUserOrderId");
+    }
+  });
+
+  it("modify_spot_order on the stop-loss", async () => {
+    await setStopLoss();
+
+    try {
+      await modifySpotOrder(STOP_LOSS_ORDER_ID);
+      assert.fail(0, 1, "modify_spot_order instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ReservedUserOrderId");
+    }
+  });
+
+  it("cancel_spot_order on the stop-loss", async () => {
+    try {
+      await cancelSpotOrder(STOP_LOSS_ORDER_ID);
+      assert.fail(0, 1, "cancel_spot_order instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ReservedUserOrderId");
+    }
+  });
+
+  it("place_spot_order, modify_spot_order and cancel_spot_order by owner", async () => {
+    await placeSpotOrder(1);
+    await modifySpotOrder(LIMIT_ORDER_ID);
+    await cancelSpotOrder(LIMIT_ORDER_ID);
+  });
+
+  it("cancel_spot_orders by non-owner", async () => {
+    const { program, provider, otherOwnerKeypair } = testSetup;
+    await refreshOracles(provider);
+
+    try {
+      await program.methods
+        .cancelSpotOrders(DRIFT_MARKET_INDEX_SOL)
+        // @ts-ignore - Causing an issue in Cursor IDE
+        .accounts(getOrderAccounts(otherOwnerKeypair.publicKey))
+        .remainingAccounts(orderRemainingAccounts())
+        .signers([otherOwnerKeypair])
+        .rpc();
+      assert.fail(0, 1, "cancel_spot_orders instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("cancel_spot_orders leaves the stop-loss in place", async () => {
+    const { program, provider, ownerKeypair } = testSetup;
+    await placeSpotOrder(2);
+    await refreshOracles(provider);
+
+    await program.methods
+      .cancelSpotOrders(DRIFT_MARKET_INDEX_SOL)
+      // @ts-ignore - Causing an issue in Cursor IDE
+      .accounts(getOrderAccounts(ownerKeypair.publicKey))
+      .remainingAccounts(orderRemainingAccounts())
+      .signers([ownerKeypair])
+      .rpc();
+
+    try {
+      await setStopLoss();
+      assert.fail(0, 1, "set_stop_loss instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("StopLossAlreadySet");
+    }
+  });
+
+  it("cancel_stop_loss by non-owner", async () => {
+    try {
+      await cancelStopLoss(testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "cancel_stop_loss instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("cancel_stop_loss by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await cancelStopLoss();
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.stopLoss).to.be.null;
+  });
+
+  it("cancel_stop_loss without a stop-loss", async () => {
+    try {
+      await cancelStopLoss();
+      assert.fail(0, 1, "cancel_stop_loss instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NoStopLoss");
+    }
+  });
+
+  describe("DCA plans", () => {
+    const planId = new anchor.BN(1);
+    const intervalSeconds = 3_600;
+    let dcaPlanPda: PublicKey;
+    let crankerKeypair: anchor.web3.Keypair;
+
+    before(async () => {
+      const { program, vaultPda, createFundedKeypair } = testSetup;
+
+      crankerKeypair = await createFundedKeypair();
+      [dcaPlanPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("dca_plan"), vaultPda.toBuffer(), planId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    });
+
+    const createDcaPlan = async (amountUsd: number) => {
+      const { program, vaultPda, ownerKeypair } = testSetup;
+
+      await program.methods
+        .createDcaPlan(planId, {
+          marketIndex: DRIFT_MARKET_INDEX_SOL,
+          side: { sell: {} },
+          amountUsd: new anchor.BN(amountUsd),
+          maxBaseAssetAmount: new anchor.BN(LAMPORTS_PER_SOL / 20),
+          limitPrice: new anchor.BN(0),
+          intervalSeconds: new anchor.BN(intervalSeconds),
+          firstDueTs: new anchor.BN(Math.floor(Date.now() / 1000) - 10),
+          endTs: new anchor.BN(0),
+        })
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          dcaPlan: dcaPlanPda,
+          owner: ownerKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+    };
+
+    const executeDcaPlan = async () => {
+      const { program, provider, vaultPda } = testSetup;
+      await refreshOracles(provider);
+
+      await program.methods
+        .executeDcaPlan()
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          dcaPlan: dcaPlanPda,
+          cranker: crankerKeypair.publicKey,
+          driftState: getDriftState(),
+          driftUser: getDriftUser(vaultPda),
+          driftProgram: DRIFT_PROGRAM_ID,
+        })
+        .remainingAccounts(orderRemainingAccounts())
+        .signers([crankerKeypair])
+        .rpc();
+    };
+
+    it("create_dca_plan without a USD amount", async () => {
+      try {
+        await createDcaPlan(0);
+        assert.fail(0, 1, "create_dca_plan instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("InvalidDcaPlan");
+      }
+    });
+
+    it("create_dca_plan by owner", async () => {
+      const { program } = testSetup;
+
+      // $15 a period, 0.1 SOL at $150, capped at 0.05 SOL per order
+      await createDcaPlan(15_000_000);
+
+      const dcaPlan = await program.account.dcaPlan.fetch(dcaPlanPda);
+      expect(dcaPlan.amountUsd.toNumber()).to.equal(15_000_000);
+    });
+
+    it("execute_dca_plan once due", async () => {
+      const { program } = testSetup;
+      const before = await program.account.dcaPlan.fetch(dcaPlanPda);
+
+      await executeDcaPlan();
+
+      const after = await program.account.dcaPlan.fetch(dcaPlanPda);
+      expect(after.nextDueTs.toNumber()).to.equal(before.nextDueTs.toNumber() + intervalSeconds);
+    });
+
+    it("execute_dca_plan before the next interval", async () => {
+      try {
+        await executeDcaPlan();
+        assert.fail(0, 1, "execute_dca_plan instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("DcaOrderNotDue");
+      }
+    });
+
+    it("cancel_dca_plan by non-owner", async () => {
+      const { program, vaultPda, otherOwnerKeypair } = testSetup;
+
+      try {
+        await program.methods
+          .cancelDcaPlan()
+          .accounts({
+            // @ts-ignore - Causing an issue in Cursor IDE
+            vault: vaultPda,
+            dcaPlan: dcaPlanPda,
+            owner: otherOwnerKeypair.publicKey,
+          })
+          .signers([otherOwnerKeypair])
+          .rpc();
+        assert.fail(0, 1, "cancel_dca_plan instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+      }
+    });
+
+    it("cancel_dca_plan by owner", async () => {
+      const { program, vaultPda, ownerKeypair, connection } = testSetup;
+
+      await program.methods
+        .cancelDcaPlan()
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          dcaPlan: dcaPlanPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      const dcaPlan = await connection.getAccountInfo(dcaPlanPda);
+      expect(dcaPlan).to.be.null;
+    });
+  });
+});
@@ -0,0 +1,69 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, Transaction, TransactionInstruction } from "@solana/web3.js";
+
+// The mock receiver is loaded at the real receiver's address, so Drift and the funds program accept its accounts
+export const PYTH_RECEIVER_PROGRAM_ID = new PublicKey("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+// Drift's pull oracle accounts, preloaded from tests/fixtures
+export const ORACLE_SOL = new PublicKey("BAtFj4kQttZRVep3UZS2aZRDixkGYgWsbqTBVDbnSsPF");
+export const ORACLE_USDC = new PublicKey("En8hkHLkRe9d9DraYmBTrus518BvmVH448YcvmrFM6Ce");
+export const ORACLE_LST = new PublicKey("9QE1P5EfzthYDgoQ9oPeTByCEKaRJeZbVVqKJfgU9iau");
+
+const DISCRIMINATOR_SIZE = 8;
+const WRITE_AUTHORITY_SIZE = 32;
+const FEED_ID_SIZE = 32;
+const VERIFICATION_LEVEL_OFFSET = DISCRIMINATOR_SIZE + WRITE_AUTHORITY_SIZE;
+
+export type PythPriceParams = {
+  price: number;
+  conf: number;
+  exponent: number;
+  publishTime: number;
+  // Partially verified updates carry the number of guardian signatures checked
+  partialSignatures?: number;
+};
+
+// Rewrites a PriceUpdateV2 fixture, keeping its discriminator, write authority and feed ID
+export const setPythPrice = async (
+  provider: anchor.AnchorProvider,
+  oracle: PublicKey,
+  { price, conf, exponent, publishTime, partialSignatures }: PythPriceParams
+) => {
+  const account = await provider.connection.getAccountInfo(oracle);
+  if (!account) throw new Error(`Oracle ${oracle.toBase58()} is not loaded`);
+
+  const currentMessageOffset = account.data[VERIFICATION_LEVEL_OFFSET] === 0
+    ? VERIFICATION_LEVEL_OFFSET + 2
+    : VERIFICATION_LEVEL_OFFSET + 1;
+  const feedId = account.data.subarray(currentMessageOffset, currentMessageOffset + FEED_ID_SIZE);
+
+  const verificationLevel = partialSignatures === undefined
+    ? Buffer.from([1])
+    : Buffer.from([0, partialSignatures]);
+
+  const message = Buffer.alloc(8 + 8 + 4 + 8 + 8 + 8 + 8 + 8);
+  message.writeBigInt64LE(BigInt(price), 0);
+  message.writeBigUInt64LE(BigInt(conf), 8);
+  message.writeInt32LE(exponent, 16);
+  message.writeBigInt64LE(BigInt(publishTime), 20);
+  message.writeBigInt64LE(BigInt(publishTime), 28);
+  message.writeBigInt64LE(BigInt(price), 36);
+  message.writeBigUInt64LE(BigInt(conf), 44);
+  message.writeBigUInt64LE(BigInt(await provider.connection.getSlot()), 52);
+
+  const data = Buffer.concat([
+    account.data.subarray(0, VERIFICATION_LEVEL_OFFSET),
+    verificationLevel,
+    feedId,
+    message,
+  ]);
+
+  const tx = new Transaction().add(
+    new TransactionInstruction({
+      programId: PYTH_RECEIVER_PROGRAM_ID,
+      keys: [{ pubkey: oracle, isSigner: false, isWritable: true }],
+      data,
+    })
+  );
+  await provider.sendAndConfirm(tx);
+};
@@ -0,0 +1,295 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+dotenv.config();
+
+// Matches RECOVERY_CANCELLATION_WINDOW_SECONDS with the local feature
+const RECOVERY_CANCELLATION_WINDOW_MS = 5_000;
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("recovery tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let guardians: Keypair[];
+  let newOwnerKeypair: Keypair;
+  let recoveryPda: PublicKey;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, vaultPda, createFundedKeypair } = testSetup;
+
+    guardians = [await createFundedKeypair(), await createFundedKeypair(), await createFundedKeypair()];
+    newOwnerKeypair = await createFundedKeypair();
+    [recoveryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("recovery"), vaultPda.toBuffer()],
+      program.programId
+    );
+  });
+
+  it("set_guardians rejects a threshold above the guardian count", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .setGuardians(guardians.map((guardian) => guardian.publicKey), 4)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          recoveryRequest: recoveryPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "set_guardians instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidGuardianThreshold");
+    }
+  });
+
+  it("set_guardians by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setGuardians(guardians.map((guardian) => guardian.publicKey), 2)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.guardians.length).to.equal(3);
+    expect(vault.guardianThreshold).to.equal(2);
+  });
+
+  it("initiate_recovery by non-guardian", async () => {
+    const { program, vaultPda, otherOwnerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .initiateRecovery(otherOwnerKeypair.publicKey)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          recoveryRequest: recoveryPda,
+          guardian: otherOwnerKeypair.publicKey,
+        })
+        .signers([otherOwnerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "initiate_recovery instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAGuardian");
+    }
+  });
+
+  it("initiate_recovery by guardian", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .initiateRecovery(newOwnerKeypair.publicKey)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        guardian: guardians[0].publicKey,
+      })
+      .signers([guardians[0]])
+      .rpc();
+
+    const recoveryRequest = await program.account.recoveryRequest.fetch(recoveryPda);
+    expect(recoveryRequest.newOwner.equals(newOwnerKeypair.publicKey)).to.be.true;
+    expect(recoveryRequest.approvals.length).to.equal(1);
+  });
+
+  it("set_guardians rejected while a recovery is pending", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .setGuardians([guardians[0].publicKey], 1)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          recoveryRequest: recoveryPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "set_guardians instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("RecoveryPending");
+    }
+  });
+
+  it("execute_recovery below threshold", async () => {
+    const { program, vaultPda } = testSetup;
+
+    try {
+      await program.methods
+        .executeRecovery()
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          recoveryRequest: recoveryPda,
+          newOwner: newOwnerKeypair.publicKey,
+          initiator: guardians[0].publicKey,
+        })
+        .signers([newOwnerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "execute_recovery instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("RecoveryThresholdNotMet");
+    }
+  });
+
+  it("approve_recovery twice by the same guardian", async () => {
+    const { program, vaultPda } = testSetup;
+
+    try {
+      await program.methods
+        .approveRecovery()
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          recoveryRequest: recoveryPda,
+          guardian: guardians[0].publicKey,
+        })
+        .signers([guardians[0]])
+        .rpc();
+
+      assert.fail(0, 1, "approve_recovery instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("RecoveryAlreadyApproved");
+    }
+  });
+
+  it("approve_recovery by second guardian", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .approveRecovery()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        guardian: guardians[1].publicKey,
+      })
+      .signers([guardians[1]])
+      .rpc();
+
+    const recoveryRequest = await program.account.recoveryRequest.fetch(recoveryPda);
+    expect(recoveryRequest.approvals.length).to.equal(2);
+  });
+
+  it("execute_recovery inside the cancellation window", async () => {
+    const { program, vaultPda } = testSetup;
+
+    try {
+      await program.methods
+        .executeRecovery()
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          recoveryRequest: recoveryPda,
+          newOwner: newOwnerKeypair.publicKey,
+          initiator: guardians[0].publicKey,
+        })
+        .signers([newOwnerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "execute_recovery instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("RecoveryWindowActive");
+    }
+  });
+
+  it("execute_recovery after the cancellation window", async () => {
+    const { program, connection, vaultPda } = testSetup;
+    await sleep(RECOVERY_CANCELLATION_WINDOW_MS + 1_000);
+
+    await program.methods
+      .executeRecovery()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        newOwner: newOwnerKeypair.publicKey,
+        initiator: guardians[0].publicKey,
+      })
+      .signers([newOwnerKeypair])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.owner.equals(newOwnerKeypair.publicKey)).to.be.true;
+    expect(await connection.getAccountInfo(recoveryPda)).to.be.null;
+  });
+
+  it("cancel_recovery by non-owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .initiateRecovery(guardians[2].publicKey)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        guardian: guardians[2].publicKey,
+      })
+      .signers([guardians[2]])
+      .rpc();
+
+    try {
+      await program.methods
+        .cancelRecovery()
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          recoveryRequest: recoveryPda,
+          owner: guardians[2].publicKey,
+          initiator: guardians[2].publicKey,
+        })
+        .signers([guardians[2]])
+        .rpc();
+
+      assert.fail(0, 1, "cancel_recovery instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("cancel_recovery by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .cancelRecovery()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        recoveryRequest: recoveryPda,
+        owner: newOwnerKeypair.publicKey,
+        initiator: guardians[2].publicKey,
+      })
+      .signers([newOwnerKeypair])
+      .rpc();
+
+    const recoveryRequest = await program.account.recoveryRequest.fetchNullable(recoveryPda);
+    expect(recoveryRequest).to.be.null;
+  });
+});
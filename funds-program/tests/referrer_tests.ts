@@ -0,0 +1,44 @@
+import { AnchorError } from "@coral-xyz/anchor";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { QUARTZ_REFERRER, getDriftUser, getDriftUserStats, initDriftAccount } from "./drift_utils";
+dotenv.config();
+
+describe("referrer tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+
+  before(async () => {
+    testSetup = await setupTests();
+  });
+
+  it("init_drift_account with only the referrer user", async () => {
+    try {
+      await initDriftAccount(testSetup, getDriftUser(QUARTZ_REFERRER), null);
+      assert.fail(0, 1, "init_drift_account instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidReferrer");
+    }
+  });
+
+  it("init_drift_account with only the referrer user stats", async () => {
+    try {
+      await initDriftAccount(testSetup, null, getDriftUserStats(QUARTZ_REFERRER));
+      assert.fail(0, 1, "init_drift_account instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidReferrer");
+    }
+  });
+
+  // The Quartz referrer has no Drift account on localnet, so the vault is created without a referrer
+  it("init_drift_account with the default referrer", async () => {
+    const { connection, vaultPda } = testSetup;
+
+    await initDriftAccount(testSetup);
+
+    expect(await connection.getAccountInfo(getDriftUser(vaultPda))).to.not.be.null;
+    expect(await connection.getAccountInfo(getDriftUserStats(vaultPda))).to.not.be.null;
+  });
+});
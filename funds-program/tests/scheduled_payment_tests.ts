@@ -0,0 +1,382 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("scheduled payment tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let recipientKeypair: Keypair;
+  let scheduledPaymentPda: PublicKey;
+  const paymentId = new anchor.BN(1);
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, vaultPda } = testSetup;
+
+    recipientKeypair = Keypair.generate();
+    [scheduledPaymentPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("scheduled_payment"), vaultPda.toBuffer(), paymentId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+  });
+
+  it("create_scheduled_payment with a zero interval", async () => {
+    const { program, vaultPda, ownerKeypair, testUsdcMint } = testSetup;
+    const now = Math.floor(Date.now() / 1000);
+
+    try {
+      await program.methods
+        .createScheduledPayment(paymentId, new anchor.BN(100), new anchor.BN(0), new anchor.BN(0), new anchor.BN(now), new anchor.BN(0))
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          scheduledPayment: scheduledPaymentPda,
+          owner: ownerKeypair.publicKey,
+          recipient: recipientKeypair.publicKey,
+          mint: testUsdcMint,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "create_scheduled_payment instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidPaymentSchedule");
+    }
+  });
+
+  it("create_scheduled_payment by owner", async () => {
+    const { program, vaultPda, ownerKeypair, testUsdcMint } = testSetup;
+    const now = Math.floor(Date.now() / 1000);
+
+    await program.methods
+      .createScheduledPayment(paymentId, new anchor.BN(100), new anchor.BN(1), new anchor.BN(60), new anchor.BN(now + 3600), new anchor.BN(0))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        scheduledPayment: scheduledPaymentPda,
+        owner: ownerKeypair.publicKey,
+        recipient: recipientKeypair.publicKey,
+        mint: testUsdcMint,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const scheduledPayment = await program.account.scheduledPayment.fetch(scheduledPaymentPda);
+    expect(scheduledPayment.recipient.equals(recipientKeypair.publicKey)).to.be.true;
+    expect(scheduledPayment.nextDueTs.toNumber()).to.equal(now + 3600);
+  });
+
+  it("cancel_scheduled_payment by non-owner", async () => {
+    const { program, vaultPda, otherOwnerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .cancelScheduledPayment()
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          scheduledPayment: scheduledPaymentPda,
+          owner: otherOwnerKeypair.publicKey,
+        })
+        .signers([otherOwnerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "cancel_scheduled_payment instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("cancel_scheduled_payment by owner", async () => {
+    const { program, connection, vaultPda, ownerKeypair } = testSetup;
+
+    await program.methods
+      .cancelScheduledPayment()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        scheduledPayment: scheduledPaymentPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const scheduledPaymentAccount = await connection.getAccountInfo(scheduledPaymentPda);
+    expect(scheduledPaymentAccount).to.be.null;
+  });
+
+  it("set_vault_paused by non-owner", async () => {
+    const { program, vaultPda, otherOwnerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .setVaultPaused(true)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          owner: otherOwnerKeypair.publicKey,
+        })
+        .signers([otherOwnerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "set_vault_paused instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_vault_paused by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setVaultPaused(true)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.paused).to.be.true;
+  });
+
+  it("set_spend_limit with a zero period", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .setSpendLimit(new anchor.BN(100_000_000), new anchor.BN(0))
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      assert.fail(0, 1, "set_spend_limit instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidSpendLimit");
+    }
+  });
+
+  it("set_spend_limit by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setSpendLimit(new anchor.BN(100_000_000), new anchor.BN(86_400))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.spendLimit.limitUsd.toNumber()).to.equal(100_000_000);
+    expect(vault.spendLimit.spentInPeriodUsd.toNumber()).to.equal(0);
+  });
+
+  describe("execute_scheduled_payment", () => {
+    const solPaymentId = new anchor.BN(2);
+    const paymentAmount = LAMPORTS_PER_SOL / 10;
+    const intervalSeconds = 60;
+    let solPaymentPda: PublicKey;
+    let crankerKeypair: Keypair;
+    let recipientWsol: PublicKey;
+    let crankerWsol: PublicKey;
+    let firstDueTs: number;
+
+    before(async () => {
+      const { program, connection, wallet, vaultPda, ownerKeypair, createFundedKeypair } = testSetup;
+
+      await program.methods
+        .setVaultPaused(false)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      await initDriftAccount(testSetup);
+      await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+      crankerKeypair = await createFundedKeypair();
+      recipientWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, recipientKeypair.publicKey)).address;
+      crankerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, crankerKeypair.publicKey)).address;
+
+      [solPaymentPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("scheduled_payment"), vaultPda.toBuffer(), solPaymentId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      // Two periods are already due and the third is half an interval away
+      firstDueTs = Math.floor(Date.now() / 1000) - 2 * intervalSeconds + intervalSeconds / 2;
+      await program.methods
+        .createScheduledPayment(solPaymentId, new anchor.BN(paymentAmount), new anchor.BN(0), new anchor.BN(intervalSeconds), new anchor.BN(firstDueTs), new anchor.BN(0))
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          scheduledPayment: solPaymentPda,
+          owner: ownerKeypair.publicKey,
+          recipient: recipientKeypair.publicKey,
+          mint: NATIVE_MINT,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+    });
+
+    const executeScheduledPayment = async () => {
+      const { program, provider, vaultPda } = testSetup;
+      await refreshOracles(provider);
+
+      return program.methods
+        .executeScheduledPayment(null)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          scheduledPayment: solPaymentPda,
+          vaultSpl: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+          cranker: crankerKeypair.publicKey,
+          crankerSpl: crankerWsol,
+          recipient: recipientKeypair.publicKey,
+          recipientSpl: recipientWsol,
+          driftState: getDriftState(),
+          driftUser: getDriftUser(vaultPda),
+          driftUserStats: getDriftUserStats(vaultPda),
+          spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+          driftSigner: getDriftSigner(),
+          mint: NATIVE_MINT,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          driftProgram: DRIFT_PROGRAM_ID,
+          constAccount: ORACLE_SOL,
+          additionalAccount: ORACLE_USDC,
+          spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+          spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+          oracle: ORACLE_SOL,
+          pythSolanaReceiver: null,
+          guardianSet: null,
+          priceFeed: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([crankerKeypair])
+        .rpc();
+    };
+
+    it("execute_scheduled_payment on a spend limit too low for the payment", async () => {
+      const { program, vaultPda, ownerKeypair } = testSetup;
+
+      // 0.1 SOL at $150 is $15
+      await program.methods
+        .setSpendLimit(new anchor.BN(10_000_000), new anchor.BN(86_400))
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      try {
+        await executeScheduledPayment();
+        assert.fail(0, 1, "execute_scheduled_payment instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("SpendLimitExceeded");
+      }
+    });
+
+    it("execute_scheduled_payment pays each missed period on its own crank", async () => {
+      const { program, connection, vaultPda, ownerKeypair } = testSetup;
+
+      await program.methods
+        .setSpendLimit(new anchor.BN(100_000_000), new anchor.BN(86_400))
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      await executeScheduledPayment();
+      await executeScheduledPayment();
+
+      const recipientAccount = await getAccount(connection, recipientWsol);
+      expect(Number(recipientAccount.amount)).to.equal(2 * paymentAmount);
+
+      const scheduledPayment = await program.account.scheduledPayment.fetch(solPaymentPda);
+      expect(scheduledPayment.nextDueTs.toNumber()).to.equal(firstDueTs + 2 * intervalSeconds);
+
+      const vault = await program.account.vault.fetch(vaultPda);
+      expect(vault.spendLimit.spentInPeriodUsd.toNumber()).to.be.closeTo(30_000_000, 2_000);
+    });
+
+    it("execute_scheduled_payment before the next period", async () => {
+      try {
+        await executeScheduledPayment();
+        assert.fail(0, 1, "execute_scheduled_payment instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("PaymentNotDue");
+      }
+    });
+
+    it("execute_scheduled_payment on a paused vault", async () => {
+      const { program, vaultPda, ownerKeypair } = testSetup;
+
+      await program.methods
+        .setVaultPaused(true)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc();
+
+      // Waits for the next period so only the pause can reject it
+      await new Promise((resolve) => setTimeout(resolve, intervalSeconds * 1000));
+
+      try {
+        await executeScheduledPayment();
+        assert.fail(0, 1, "execute_scheduled_payment instruction call should have failed");
+      } catch (err) {
+        expect(err).to.be.instanceOf(AnchorError);
+        expect((err as AnchorError).error.errorCode.code).to.equal("VaultPaused");
+      }
+    });
+  });
+});
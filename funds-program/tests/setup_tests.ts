@@ -88,6 +88,20 @@ export const setupTests = async () => {
     );
   };
 
+  // Setup function to create and fund a fresh keypair, e.g. for guardians or co-owners
+  const createFundedKeypair = async (lamports: number = LAMPORTS_PER_SOL) => {
+    const keypair = Keypair.generate();
+    const tx_keypairTopup = new Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: wallet.publicKey,
+        toPubkey: keypair.publicKey,
+        lamports,
+      })
+    );
+    await provider.sendAndConfirm(tx_keypairTopup);
+    return keypair;
+  };
+
   // Perform initial setup
   await performInitialSetup();
 
@@ -128,10 +142,8 @@ export const setupTests = async () => {
       .accounts({
         // @ts-ignore - Causing an issue in Cursor IDE
         vault: vaultPda,
-        vaultUsdc: vaultUsdcPda,
         owner: ownerKeypair.publicKey,
-        usdcMint: testUsdcMint,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerRegistry: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([ownerKeypair])
@@ -157,5 +169,6 @@ export const setupTests = async () => {
     otherKeypairVaultUsdcPda,
     CENT_PER_USDC,
     mintUsdcToVault,
+    createFundedKeypair,
   };
 };
\ No newline at end of file
@@ -0,0 +1,221 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("spend tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let cardManagerKeypair: Keypair;
+  let cardManagerWsol: PublicKey;
+  let allowancePda: PublicKey;
+  let cardPda: PublicKey;
+  const cardId = new anchor.BN(1);
+  const spendAmount = LAMPORTS_PER_SOL / 10;
+  const blockedMcc = 7995;
+  const groceryMcc = 5411;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, connection, wallet, vaultPda, ownerKeypair, createFundedKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    cardManagerKeypair = await createFundedKeypair();
+    cardManagerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, cardManagerKeypair.publicKey)).address;
+
+    [allowancePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("allowance"), vaultPda.toBuffer(), cardManagerKeypair.publicKey.toBuffer(), NATIVE_MINT.toBuffer()],
+      program.programId
+    );
+    [cardPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("card"), vaultPda.toBuffer(), cardId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .approveAllowance(cardManagerKeypair.publicKey, NATIVE_MINT, { card: {} }, new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        allowance: allowancePda,
+        owner: ownerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const expiryTs = Math.floor(Date.now() / 1000) + 86_400;
+    await program.methods
+      .issueCard(cardId, new anchor.BN(100_000_000), new anchor.BN(0), new anchor.BN(expiryTs))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        card: cardPda,
+        owner: ownerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  });
+
+  const getWithdrawAccounts = () => {
+    const { program, vaultPda } = testSetup;
+
+    return {
+      vault: vaultPda,
+      allowance: allowancePda,
+      vaultSpl: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+      spender: cardManagerKeypair.publicKey,
+      spenderSpl: cardManagerWsol,
+      driftState: getDriftState(),
+      driftUser: getDriftUser(vaultPda),
+      driftUserStats: getDriftUserStats(vaultPda),
+      spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+      driftSigner: getDriftSigner(),
+      mint: NATIVE_MINT,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      driftProgram: DRIFT_PROGRAM_ID,
+      constAccount: ORACLE_SOL,
+      additionalAccount: ORACLE_USDC,
+      spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+      spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+      oracle: ORACLE_SOL,
+      pythSolanaReceiver: null,
+      guardianSet: null,
+      priceFeed: null,
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  const spend = async (spendId: number, mcc: number) => {
+    const { program, provider, vaultPda } = testSetup;
+    await refreshOracles(provider);
+
+    const spendIdBn = new anchor.BN(spendId);
+    const [spendRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("spend_record"), vaultPda.toBuffer(), spendIdBn.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .spend(spendIdBn, new anchor.BN(spendAmount), mcc, Array(32).fill(0), null)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getWithdrawAccounts(),
+        spendRecord,
+        card: cardPda,
+      })
+      .signers([cardManagerKeypair])
+      .rpc();
+
+    return spendRecord;
+  };
+
+  it("set_spend_rules by non-owner", async () => {
+    const { program, vaultPda, otherOwnerKeypair } = testSetup;
+
+    try {
+      await program.methods
+        .setSpendRules([blockedMcc], [], [])
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          vault: vaultPda,
+          owner: otherOwnerKeypair.publicKey,
+        })
+        .signers([otherOwnerKeypair])
+        .rpc();
+      assert.fail(0, 1, "set_spend_rules instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_spend_rules by owner", async () => {
+    const { program, vaultPda, ownerKeypair } = testSetup;
+
+    await program.methods
+      .setSpendRules([blockedMcc], [], [{ mcc: groceryMcc, monthlyCapUsd: new anchor.BN(50_000_000) }])
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: ownerKeypair.publicKey,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.blockedMccs).to.deep.equal([blockedMcc]);
+    expect(vault.categoryCaps[0].mcc).to.equal(groceryMcc);
+  });
+
+  it("spend in a blocked merchant category", async () => {
+    try {
+      await spend(1, blockedMcc);
+      assert.fail(0, 1, "spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("MerchantCategoryBlocked");
+    }
+  });
+
+  it("spend through a card allowance", async () => {
+    const { program, connection, vaultPda } = testSetup;
+
+    const spendRecordPda = await spend(2, groceryMcc);
+
+    const cardManagerAccount = await getAccount(connection, cardManagerWsol);
+    expect(Number(cardManagerAccount.amount)).to.equal(spendAmount);
+
+    // 0.1 SOL at $150 is $15
+    const spendRecord = await program.account.spendRecord.fetch(spendRecordPda);
+    expect(spendRecord.amountUsd.toNumber()).to.be.closeTo(15_000_000, 1_000);
+
+    const card = await program.account.card.fetch(cardPda);
+    expect(card.spentInPeriodUsd.toNumber()).to.equal(spendRecord.amountUsd.toNumber());
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.categoryCaps[0].spentInPeriodUsd.toNumber()).to.equal(spendRecord.amountUsd.toNumber());
+  });
+
+  it("pull_payment through a card allowance", async () => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    try {
+      await program.methods
+        .pullPayment(new anchor.BN(spendAmount), null)
+        .accounts({
+          // @ts-ignore - Causing an issue in Cursor IDE
+          ...getWithdrawAccounts(),
+        })
+        .signers([cardManagerKeypair])
+        .rpc();
+      assert.fail(0, 1, "pull_payment instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidAllowanceKind");
+    }
+  });
+});
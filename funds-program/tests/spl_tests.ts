@@ -0,0 +1,148 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+// The localnet clone has no spot market for a mint outside SOL, USDC and the LST, so these
+// only reach the owner check and the dedicated-market check, using wSOL as the mint
+describe("spl tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let ownerWsol: PublicKey;
+  let otherOwnerWsol: PublicKey;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { connection, wallet, ownerKeypair, otherOwnerKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    ownerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, ownerKeypair.publicKey)).address;
+    otherOwnerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, otherOwnerKeypair.publicKey)).address;
+  });
+
+  const getSplAccounts = (owner: Keypair, ownerSpl: PublicKey) => {
+    const { program, vaultPda } = testSetup;
+
+    return {
+      vault: vaultPda,
+      vaultSpl: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+      owner: owner.publicKey,
+      ownerSpl,
+      driftState: getDriftState(),
+      driftUser: getDriftUser(vaultPda),
+      driftUserStats: getDriftUserStats(vaultPda),
+      spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+      mint: NATIVE_MINT,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      driftProgram: DRIFT_PROGRAM_ID,
+      oracle: ORACLE_SOL,
+      spotMarket: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  const depositSpl = async (owner: Keypair, ownerSpl: PublicKey) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .depositSpl(new anchor.BN(LAMPORTS_PER_SOL / 10), false)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getSplAccounts(owner, ownerSpl),
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const withdrawSpl = async (owner: Keypair, ownerSpl: PublicKey) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .withdrawSpl(new anchor.BN(LAMPORTS_PER_SOL / 10), false, null)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getSplAccounts(owner, ownerSpl),
+        driftSigner: getDriftSigner(),
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        pythSolanaReceiver: null,
+        guardianSet: null,
+        priceFeed: null,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  it("deposit_spl by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await depositSpl(otherOwnerKeypair, otherOwnerWsol);
+      assert.fail(0, 1, "deposit_spl instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("deposit_spl with a mint that has a dedicated instruction", async () => {
+    const { ownerKeypair } = testSetup;
+
+    try {
+      await depositSpl(ownerKeypair, ownerWsol);
+      assert.fail(0, 1, "deposit_spl instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidMarketIndex");
+    }
+  });
+
+  it("withdraw_spl by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await withdrawSpl(otherOwnerKeypair, otherOwnerWsol);
+      assert.fail(0, 1, "withdraw_spl instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("withdraw_spl with a mint that has a dedicated instruction", async () => {
+    const { ownerKeypair } = testSetup;
+
+    try {
+      await withdrawSpl(ownerKeypair, ownerWsol);
+      assert.fail(0, 1, "withdraw_spl instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidMarketIndex");
+    }
+  });
+});
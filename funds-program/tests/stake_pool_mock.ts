@@ -0,0 +1,19 @@
+import { PublicKey } from "@solana/web3.js";
+
+// The mock stake pool is loaded at the SPL stake pool address, so the JitoSOL mint accepts it as the mint authority
+export const STAKE_POOL_PROGRAM_ID = new PublicKey("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+// Cloned from mainnet with its Drift spot market, see Anchor.toml
+export const LST_MINT = new PublicKey("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn");
+export const LST_STAKE_POOL = new PublicKey("Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb");
+
+export const getStakePoolWithdrawAuthority = () => PublicKey.findProgramAddressSync(
+  [LST_STAKE_POOL.toBuffer(), Buffer.from("withdraw")],
+  STAKE_POOL_PROGRAM_ID
+)[0];
+
+// Preloaded from tests/fixtures, the stand-in holds staked lamports here and pays unstakes from it
+export const getStakePoolReserve = () => PublicKey.findProgramAddressSync(
+  [LST_STAKE_POOL.toBuffer(), Buffer.from("reserve")],
+  STAKE_POOL_PROGRAM_ID
+)[0];
@@ -0,0 +1,388 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAccount, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  depositLamports,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("team tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let memberKeypair: Keypair;
+  let memberWsol: PublicKey;
+  let teamMemberPda: PublicKey;
+  // 0.05 SOL at $150 is $7.50, under the $10 threshold, and 0.1 SOL is $15, over it
+  const smallSpend = LAMPORTS_PER_SOL / 20;
+  const largeSpend = LAMPORTS_PER_SOL / 10;
+  const approvalThresholdUsd = 10_000_000;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { program, connection, wallet, vaultPda, createFundedKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+    await depositLamports(testSetup, LAMPORTS_PER_SOL);
+
+    memberKeypair = await createFundedKeypair();
+    memberWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, memberKeypair.publicKey)).address;
+
+    [teamMemberPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("team_member"), vaultPda.toBuffer(), memberKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+  });
+
+  const getPendingApproval = (requestId: anchor.BN) => anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_approval"), testSetup.vaultPda.toBuffer(), requestId.toArrayLike(Buffer, "le", 8)],
+    testSetup.program.programId
+  )[0];
+
+  const setTeamMode = async (owner: Keypair, enabled: boolean) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .setTeamMode(enabled, new anchor.BN(approvalThresholdUsd))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        owner: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const addTeamMember = async (owner: Keypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .addTeamMember(memberKeypair.publicKey, false, new anchor.BN(100_000_000), new anchor.BN(86_400))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        teamMember: teamMemberPda,
+        owner: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const removeTeamMember = async (owner: Keypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .removeTeamMember()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        teamMember: teamMemberPda,
+        owner: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const requestTeamSpend = async (requestId: anchor.BN, amount: number) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .requestTeamSpend(requestId, new anchor.BN(amount))
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        teamMember: teamMemberPda,
+        pendingApproval: getPendingApproval(requestId),
+        member: memberKeypair.publicKey,
+        mint: NATIVE_MINT,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([memberKeypair])
+      .rpc();
+  };
+
+  const approveTeamSpend = async (requestId: anchor.BN, approver: Keypair, approverMember: PublicKey | null) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .approveTeamSpend()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        pendingApproval: getPendingApproval(requestId),
+        approver: approver.publicKey,
+        approverMember,
+      })
+      .signers([approver])
+      .rpc();
+  };
+
+  const rejectTeamSpend = async (requestId: anchor.BN, approver: Keypair) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .rejectTeamSpend()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        pendingApproval: getPendingApproval(requestId),
+        member: memberKeypair.publicKey,
+        approver: approver.publicKey,
+        approverMember: null,
+      })
+      .signers([approver])
+      .rpc();
+  };
+
+  const teamSpend = async (spendId: number, amount: number, pendingApproval: PublicKey | null) => {
+    const { program, provider, vaultPda } = testSetup;
+    await refreshOracles(provider);
+
+    const spendIdBn = new anchor.BN(spendId);
+    const [teamSpendRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("team_spend"), vaultPda.toBuffer(), spendIdBn.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .teamSpend(spendIdBn, new anchor.BN(amount), null)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: vaultPda,
+        teamMember: teamMemberPda,
+        pendingApproval,
+        teamSpendRecord,
+        vaultSpl: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+        member: memberKeypair.publicKey,
+        memberSpl: memberWsol,
+        driftState: getDriftState(),
+        driftUser: getDriftUser(vaultPda),
+        driftUserStats: getDriftUserStats(vaultPda),
+        spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+        driftSigner: getDriftSigner(),
+        mint: NATIVE_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        driftProgram: DRIFT_PROGRAM_ID,
+        constAccount: ORACLE_SOL,
+        additionalAccount: ORACLE_USDC,
+        spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+        spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+        oracle: ORACLE_SOL,
+        pythSolanaReceiver: null,
+        guardianSet: null,
+        priceFeed: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([memberKeypair])
+      .rpc();
+
+    return teamSpendRecord;
+  };
+
+  it("add_team_member by non-owner", async () => {
+    try {
+      await addTeamMember(testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "add_team_member instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("add_team_member by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await addTeamMember(testSetup.ownerKeypair);
+
+    const teamMember = await program.account.teamMember.fetch(teamMemberPda);
+    expect(teamMember.vault.toBase58()).to.equal(vaultPda.toBase58());
+    expect(teamMember.member.toBase58()).to.equal(memberKeypair.publicKey.toBase58());
+    expect(teamMember.isAdmin).to.be.false;
+  });
+
+  it("request_team_spend with team mode off", async () => {
+    try {
+      await requestTeamSpend(new anchor.BN(1), largeSpend);
+      assert.fail(0, 1, "request_team_spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("TeamModeDisabled");
+    }
+  });
+
+  it("set_team_mode by non-owner", async () => {
+    try {
+      await setTeamMode(testSetup.otherOwnerKeypair, true);
+      assert.fail(0, 1, "set_team_mode instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("set_team_mode by owner", async () => {
+    const { program, vaultPda } = testSetup;
+
+    await setTeamMode(testSetup.ownerKeypair, true);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.teamMode).to.be.true;
+    expect(vault.teamApprovalThresholdUsd.toNumber()).to.equal(approvalThresholdUsd);
+  });
+
+  it("team_spend under the approval threshold", async () => {
+    const { program, connection } = testSetup;
+
+    const teamSpendRecordPda = await teamSpend(1, smallSpend, null);
+
+    const memberAccount = await getAccount(connection, memberWsol);
+    expect(Number(memberAccount.amount)).to.equal(smallSpend);
+
+    const teamSpendRecord = await program.account.teamSpendRecord.fetch(teamSpendRecordPda);
+    expect(teamSpendRecord.approver).to.be.null;
+
+    const teamMember = await program.account.teamMember.fetch(teamMemberPda);
+    expect(teamMember.spentInPeriodUsd.toNumber()).to.equal(teamSpendRecord.amountUsd.toNumber());
+  });
+
+  it("team_spend over the approval threshold without an approval", async () => {
+    try {
+      await teamSpend(2, largeSpend, null);
+      assert.fail(0, 1, "team_spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("TeamSpendNeedsApproval");
+    }
+  });
+
+  it("request_team_spend by member", async () => {
+    const { program } = testSetup;
+    const requestId = new anchor.BN(1);
+
+    await requestTeamSpend(requestId, largeSpend);
+
+    const pendingApproval = await program.account.pendingApproval.fetch(getPendingApproval(requestId));
+    expect(pendingApproval.member.toBase58()).to.equal(memberKeypair.publicKey.toBase58());
+    expect(pendingApproval.amount.toNumber()).to.equal(largeSpend);
+    expect(pendingApproval.approver).to.be.null;
+  });
+
+  it("approve_team_spend by a member who isn't an admin", async () => {
+    try {
+      await approveTeamSpend(new anchor.BN(1), memberKeypair, teamMemberPda);
+      assert.fail(0, 1, "approve_team_spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotATeamAdmin");
+    }
+  });
+
+  it("approve_team_spend by owner", async () => {
+    const { program, ownerKeypair } = testSetup;
+    const requestId = new anchor.BN(1);
+
+    await approveTeamSpend(requestId, ownerKeypair, null);
+
+    const pendingApproval = await program.account.pendingApproval.fetch(getPendingApproval(requestId));
+    expect(pendingApproval.approver.toBase58()).to.equal(ownerKeypair.publicKey.toBase58());
+  });
+
+  it("team_spend with an approval", async () => {
+    const { program, connection, ownerKeypair } = testSetup;
+    const pendingApproval = getPendingApproval(new anchor.BN(1));
+
+    const teamSpendRecordPda = await teamSpend(3, largeSpend, pendingApproval);
+
+    const memberAccount = await getAccount(connection, memberWsol);
+    expect(Number(memberAccount.amount)).to.equal(smallSpend + largeSpend);
+
+    const teamSpendRecord = await program.account.teamSpendRecord.fetch(teamSpendRecordPda);
+    expect(teamSpendRecord.approver.toBase58()).to.equal(ownerKeypair.publicKey.toBase58());
+
+    const pendingApprovalAccount = await connection.getAccountInfo(pendingApproval);
+    expect(pendingApprovalAccount).to.be.null;
+  });
+
+  it("reject_team_spend by neither the owner, an admin nor the member", async () => {
+    const requestId = new anchor.BN(2);
+    await requestTeamSpend(requestId, largeSpend);
+
+    try {
+      await rejectTeamSpend(requestId, testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "reject_team_spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotATeamAdmin");
+    }
+  });
+
+  it("reject_team_spend by owner", async () => {
+    const { connection, ownerKeypair } = testSetup;
+    const requestId = new anchor.BN(2);
+
+    await rejectTeamSpend(requestId, ownerKeypair);
+
+    const pendingApprovalAccount = await connection.getAccountInfo(getPendingApproval(requestId));
+    expect(pendingApprovalAccount).to.be.null;
+  });
+
+  it("approve_team_spend before the member is removed", async () => {
+    const { program, ownerKeypair } = testSetup;
+    const requestId = new anchor.BN(3);
+
+    await requestTeamSpend(requestId, largeSpend);
+    await approveTeamSpend(requestId, ownerKeypair, null);
+
+    const pendingApproval = await program.account.pendingApproval.fetch(getPendingApproval(requestId));
+    expect(pendingApproval.approver.toBase58()).to.equal(ownerKeypair.publicKey.toBase58());
+  });
+
+  it("remove_team_member by non-owner", async () => {
+    try {
+      await removeTeamMember(testSetup.otherOwnerKeypair);
+      assert.fail(0, 1, "remove_team_member instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("remove_team_member by owner", async () => {
+    const { connection } = testSetup;
+
+    await removeTeamMember(testSetup.ownerKeypair);
+
+    const teamMemberAccount = await connection.getAccountInfo(teamMemberPda);
+    expect(teamMemberAccount).to.be.null;
+  });
+
+  it("team_spend with an approval from before the member was re-added", async () => {
+    // Re-adding in a later second than the request, so the approval is older than the new membership
+    await new Promise((resolve) => setTimeout(resolve, 1_000));
+    await addTeamMember(testSetup.ownerKeypair);
+
+    try {
+      await teamSpend(4, largeSpend, getPendingApproval(new anchor.BN(3)));
+      assert.fail(0, 1, "team_spend instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("StalePendingApproval");
+    }
+  });
+});
@@ -0,0 +1,115 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+dotenv.config();
+
+describe("vault id tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+
+  before(async () => {
+    testSetup = await setupTests();
+  });
+
+  const getOwnerRegistry = (owner: PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("owner_registry"), owner.toBuffer()],
+    testSetup.program.programId
+  )[0];
+
+  const getVault = (owner: PublicKey, vaultId: number) => anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), owner.toBuffer(), new anchor.BN(vaultId).toArrayLike(Buffer, "le", 2)],
+    testSetup.program.programId
+  )[0];
+
+  const initOwnerRegistry = async (owner: Keypair, ownerRegistry: PublicKey) => {
+    const { program, vaultPda } = testSetup;
+
+    await program.methods
+      .initOwnerRegistry()
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ownerRegistry,
+        firstVault: vaultPda,
+        owner: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const initVault = async (vaultId: number) => {
+    const { program, ownerKeypair } = testSetup;
+
+    await program.methods
+      .initVault(vaultId)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        vault: getVault(ownerKeypair.publicKey, vaultId),
+        ownerRegistry: getOwnerRegistry(ownerKeypair.publicKey),
+        owner: ownerKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ownerKeypair])
+      .rpc();
+  };
+
+  it("init_vault before the owner registry", async () => {
+    try {
+      await initVault(1);
+      assert.fail(0, 1, "init_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("AccountNotInitialized");
+    }
+  });
+
+  it("init_owner_registry at another owner's address", async () => {
+    const { ownerKeypair, otherOwnerKeypair } = testSetup;
+
+    try {
+      await initOwnerRegistry(ownerKeypair, getOwnerRegistry(otherOwnerKeypair.publicKey));
+      assert.fail(0, 1, "init_owner_registry instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("ConstraintSeeds");
+    }
+  });
+
+  it("init_owner_registry by owner", async () => {
+    const { program, ownerKeypair } = testSetup;
+    const ownerRegistryPda = getOwnerRegistry(ownerKeypair.publicKey);
+
+    await initOwnerRegistry(ownerKeypair, ownerRegistryPda);
+
+    // The vault from init_user is listed as vault 0
+    const ownerRegistry = await program.account.ownerRegistry.fetch(ownerRegistryPda);
+    expect(ownerRegistry.owner.toBase58()).to.equal(ownerKeypair.publicKey.toBase58());
+    expect(ownerRegistry.vaultIds).to.deep.equal([0]);
+  });
+
+  it("init_vault with vault ID 0", async () => {
+    try {
+      await initVault(0);
+      assert.fail(0, 1, "init_vault instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidVaultId");
+    }
+  });
+
+  it("init_vault by owner", async () => {
+    const { program, ownerKeypair } = testSetup;
+    const vaultPda = getVault(ownerKeypair.publicKey, 1);
+
+    await initVault(1);
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(vault.owner.toBase58()).to.equal(ownerKeypair.publicKey.toBase58());
+    expect(vault.vaultId).to.equal(1);
+
+    const ownerRegistry = await program.account.ownerRegistry.fetch(getOwnerRegistry(ownerKeypair.publicKey));
+    expect(ownerRegistry.vaultIds).to.deep.equal([0, 1]);
+  });
+});
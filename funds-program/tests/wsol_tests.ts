@@ -0,0 +1,158 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  NATIVE_MINT,
+  TOKEN_PROGRAM_ID,
+  createSyncNativeInstruction,
+  getAccount,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import dotenv from 'dotenv';
+import { assert, expect } from "chai";
+import { setupTests } from "./setup_tests";
+import { ORACLE_SOL, ORACLE_USDC } from "./pyth_mock";
+import {
+  DRIFT_MARKET_INDEX_SOL,
+  DRIFT_MARKET_INDEX_USDC,
+  DRIFT_PROGRAM_ID,
+  getDriftSigner,
+  getDriftState,
+  getDriftUser,
+  getDriftUserStats,
+  getSpotMarket,
+  getSpotMarketVault,
+  getVaultTokenAccount,
+  initDriftAccount,
+  refreshOracles
+} from "./drift_utils";
+dotenv.config();
+
+describe("wsol tests", () => {
+  let testSetup: Awaited<ReturnType<typeof setupTests>>;
+  let ownerWsol: PublicKey;
+  let otherOwnerWsol: PublicKey;
+  const wrappedAmount = LAMPORTS_PER_SOL;
+  const depositAmount = LAMPORTS_PER_SOL / 2;
+  const withdrawAmount = LAMPORTS_PER_SOL / 4;
+
+  before(async () => {
+    testSetup = await setupTests();
+    const { provider, connection, wallet, ownerKeypair, otherOwnerKeypair } = testSetup;
+
+    await initDriftAccount(testSetup);
+
+    ownerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, ownerKeypair.publicKey)).address;
+    otherOwnerWsol = (await getOrCreateAssociatedTokenAccount(connection, wallet.payer, NATIVE_MINT, otherOwnerKeypair.publicKey)).address;
+
+    // Wraps SOL into the owner's wSOL account
+    await provider.sendAndConfirm(new Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: wallet.publicKey,
+        toPubkey: ownerWsol,
+        lamports: wrappedAmount,
+      }),
+      createSyncNativeInstruction(ownerWsol)
+    ));
+  });
+
+  const getWsolAccounts = (owner: Keypair, ownerWsol: PublicKey) => {
+    const { program, vaultPda } = testSetup;
+
+    return {
+      vault: vaultPda,
+      vaultWsol: getVaultTokenAccount(program.programId, vaultPda, NATIVE_MINT),
+      owner: owner.publicKey,
+      ownerWsol,
+      driftState: getDriftState(),
+      driftUser: getDriftUser(vaultPda),
+      driftUserStats: getDriftUserStats(vaultPda),
+      spotMarketVault: getSpotMarketVault(DRIFT_MARKET_INDEX_SOL),
+      wsolMint: NATIVE_MINT,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      driftProgram: DRIFT_PROGRAM_ID,
+      constAccount: ORACLE_SOL,
+      additionalAccount: ORACLE_USDC,
+      spotMarketSol: getSpotMarket(DRIFT_MARKET_INDEX_SOL),
+      spotMarketUsdc: getSpotMarket(DRIFT_MARKET_INDEX_USDC),
+      systemProgram: SystemProgram.programId,
+    };
+  };
+
+  const depositWsol = async (owner: Keypair, ownerWsol: PublicKey) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .depositWsol(new anchor.BN(depositAmount), false)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getWsolAccounts(owner, ownerWsol),
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  const withdrawWsol = async (owner: Keypair, ownerWsol: PublicKey) => {
+    const { program, provider } = testSetup;
+    await refreshOracles(provider);
+
+    await program.methods
+      .withdrawWsol(new anchor.BN(withdrawAmount), false, null)
+      .accounts({
+        // @ts-ignore - Causing an issue in Cursor IDE
+        ...getWsolAccounts(owner, ownerWsol),
+        driftSigner: getDriftSigner(),
+        pythSolanaReceiver: null,
+        guardianSet: null,
+        priceFeed: null,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
+  it("deposit_wsol by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await depositWsol(otherOwnerKeypair, otherOwnerWsol);
+      assert.fail(0, 1, "deposit_wsol instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("deposit_wsol by owner", async () => {
+    const { connection, ownerKeypair } = testSetup;
+
+    await depositWsol(ownerKeypair, ownerWsol);
+
+    // The SOL stays wrapped on the way in, so the owner's wSOL account is debited directly
+    const ownerWsolAccount = await getAccount(connection, ownerWsol);
+    expect(Number(ownerWsolAccount.amount)).to.equal(wrappedAmount - depositAmount);
+  });
+
+  it("withdraw_wsol by non-owner", async () => {
+    const { otherOwnerKeypair } = testSetup;
+
+    try {
+      await withdrawWsol(otherOwnerKeypair, otherOwnerWsol);
+      assert.fail(0, 1, "withdraw_wsol instruction call should have failed");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("NotAVaultOwner");
+    }
+  });
+
+  it("withdraw_wsol by owner", async () => {
+    const { connection, ownerKeypair } = testSetup;
+
+    await withdrawWsol(ownerKeypair, ownerWsol);
+
+    const ownerWsolAccount = await getAccount(connection, ownerWsol);
+    expect(Number(ownerWsolAccount.amount)).to.equal(wrappedAmount - depositAmount + withdrawAmount);
+  });
+});